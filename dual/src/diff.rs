@@ -0,0 +1,99 @@
+//! Derivatives of generic functions at a point, the function is written once against the tech traits
+//! (and Elementary) and called with dual numbers. A Polynomial is evaluated at a dual number with `eval_in`,
+//! its `eval` needs a DivisionRing
+
+use crate::{dl::{Dual, HyperDual}, vector::DualVec};
+use tech::UnRing;
+
+/// `f'(x)`
+pub fn derivative<T: UnRing + Clone>(f: impl Fn(Dual<T>) -> Dual<T>, x: T) -> T {
+    f(Dual::variable(x)).du().clone()
+}
+
+/// `f''(x)` through the hyper-dual `x + ε1 + ε2`, exact up to the rounding of T
+pub fn second_derivative<T: UnRing + Clone>(f: impl Fn(HyperDual<T>) -> HyperDual<T>, x: T) -> T {
+    let x = Dual::new(Dual::variable(x), Dual::constant(T::one()));
+
+    f(x).du().du().clone()
+}
+
+/// All the partial derivatives of f at x
+pub fn gradient<T: UnRing + Clone, const N: usize>(f: impl Fn([DualVec<T, N>; N]) -> DualVec<T, N>, x: [T; N]) -> [T; N] {
+    f(DualVec::variables(x)).du().clone()
+}
+
+/// The matrix of the second partial derivatives, `hessian[i][j]` is f differentiated by `x[i]` and `x[j]`
+pub fn hessian<T: UnRing + Clone, const N: usize>(
+    f: impl Fn([DualVec<DualVec<T, N>, N>; N]) -> DualVec<DualVec<T, N>, N>,
+    x: [T; N],
+) -> [[T; N]; N] {
+    let x = DualVec::variables(DualVec::variables(x));
+
+    f(x).du().clone().map(|d| d.du().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dl::{Dual, HyperDual}, vector::DualVec, elementary::Elementary, diff::*};
+    use polynomial::{any_pnm::Polynomial, polynom};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * (1.0 + b.abs())
+    }
+
+    #[test]
+    fn derivatives_of_elementary_functions() {
+        let x = 1.3f64;
+
+        assert!(close(derivative(|x: Dual<f64>| x * x * x * x.sin(), x), 3.0 * x * x * x.sin() + x.powi(3) * x.cos()));
+        assert!(close(derivative(|x: Dual<f64>| x.ln() / x, x), (1.0 - x.ln()) / (x * x)));
+        assert!(close(derivative(|x: Dual<f64>| x.sqrt().exp(), x), x.sqrt().exp() / (2.0 * x.sqrt())));
+        assert!(close(derivative(|x: Dual<f64>| x.tan() + x.atan() + x.tanh(), x), 1.0 / (x.cos() * x.cos()) + 1.0 / (1.0 + x * x) + 1.0 / (x.cosh() * x.cosh())));
+        assert!(close(derivative(|x: Dual<f64>| x.powi(-2), x), -2.0 / x.powi(3)));
+    }
+
+    #[test]
+    fn derivatives_of_powf() {
+        //a negative base with a constant exponent
+        assert_eq!(derivative(|x: Dual<f64>| x.powf(&Dual::constant(2.0)), -3.0), -6.0);
+
+        //x^x = exp(x ln x)
+        assert!(close(derivative(|x: Dual<f64>| x.powf(&x), 2.0), 4.0 * (2f64.ln() + 1.0)));
+
+        //2^x
+        assert!(close(derivative(|x: Dual<f64>| Dual::constant(2.0).powf(&x), 3.0), 8.0 * 2f64.ln()));
+    }
+
+    #[test]
+    fn second_derivatives() {
+        let x = 0.5f64;
+
+        //(x^2 e^x)'' = (x^2 + 4x + 2) e^x
+        assert!(close(second_derivative(|x: HyperDual<f64>| x * x * x.exp(), x), (x * x + 4.0 * x + 2.0) * x.exp()));
+        assert!(close(second_derivative(|x: HyperDual<f64>| x.sin(), x), -x.sin()));
+        assert!(close(second_derivative(|x: HyperDual<f64>| x.powf(&HyperDual::from_f64(2.5)), x), 2.5 * 1.5 * x.powf(0.5)));
+    }
+
+    #[test]
+    fn gradient_and_hessian() {
+        //x y + x sin z
+        let grad = gradient(|[x, y, z]: [DualVec<f64, 3>; 3]| x * y + x * z.sin(), [2.0, 3.0, 0.5]);
+        let expected = [3.0 + 0.5f64.sin(), 2.0, 2.0 * 0.5f64.cos()];
+
+        assert!(grad.iter().zip(expected).all(|(a, b)| close(*a, b)));
+
+        //x^2 y + y^3
+        let hess = hessian(|[x, y]| x * x * y + y.powi(3), [2.0, 3.0]);
+
+        assert_eq!(hess, [[6.0, 4.0], [4.0, 18.0]]);
+    }
+
+    #[test]
+    fn derivative_of_polynomial() {
+        //x^3 - 2x + 5 at 2
+        let p: Polynomial<f64> = polynom![1.0, 0.0, -2.0, 5.0];
+        let res = p.eval_in(&Dual::variable(2.0));
+
+        assert_eq!((*res.re(), *res.du()), (9.0, 10.0));
+    }
+}
@@ -0,0 +1,169 @@
+//! Elementary functions of floats and of dual numbers over them, the dual versions carry the derivative
+//! by the chain rule
+
+use crate::{dl::Dual, vector::DualVec};
+use tech::UnRing;
+use std::ops::Div;
+
+/// Functions that generic code differentiated by Dual can call on its arguments
+pub trait Elementary: UnRing + Div<Output = Self> + Clone {
+    /// A constant of the type, for the literals of generic code
+    fn from_f64(x: f64) -> Self;
+
+    fn exp(&self) -> Self;
+
+    /// The natural logarithm
+    fn ln(&self) -> Self;
+
+    fn sqrt(&self) -> Self;
+
+    fn sin(&self) -> Self;
+
+    fn cos(&self) -> Self;
+
+    fn tan(&self) -> Self;
+
+    fn atan(&self) -> Self;
+
+    fn tanh(&self) -> Self;
+
+    fn powi(&self, n: i32) -> Self;
+
+    /// `exp(n * ln(self))`, so only for a positive self unless the type overrides it
+    fn powf(&self, n: &Self) -> Self {
+        (n.clone() * self.ln()).exp()
+    }
+}
+
+/// A macro for implementing Elementary for the float types through their own methods
+macro_rules! implFloat {
+    ($($typ: ident),*) => {
+        $(
+            impl Elementary for $typ {
+                fn from_f64(x: f64) -> Self {
+                    x as $typ
+                }
+
+                fn exp(&self) -> Self {
+                    <$typ>::exp(*self)
+                }
+
+                fn ln(&self) -> Self {
+                    <$typ>::ln(*self)
+                }
+
+                fn sqrt(&self) -> Self {
+                    <$typ>::sqrt(*self)
+                }
+
+                fn sin(&self) -> Self {
+                    <$typ>::sin(*self)
+                }
+
+                fn cos(&self) -> Self {
+                    <$typ>::cos(*self)
+                }
+
+                fn tan(&self) -> Self {
+                    <$typ>::tan(*self)
+                }
+
+                fn atan(&self) -> Self {
+                    <$typ>::atan(*self)
+                }
+
+                fn tanh(&self) -> Self {
+                    <$typ>::tanh(*self)
+                }
+
+                fn powi(&self, n: i32) -> Self {
+                    <$typ>::powi(*self, n)
+                }
+
+                fn powf(&self, n: &Self) -> Self {
+                    <$typ>::powf(*self, *n)
+                }
+            }
+        )*
+    };
+}
+
+implFloat!(f32, f64);
+
+/// A macro for implementing Elementary for the dual types, `f(re) + f'(re) du ε` for every f
+macro_rules! implDual {
+    ($([$($gen: tt)*] $typ: ty),*) => {
+        $(
+            impl<T: Elementary, $($gen)*> Elementary for $typ {
+                fn from_f64(x: f64) -> Self {
+                    Self::constant(T::from_f64(x))
+                }
+
+                fn exp(&self) -> Self {
+                    let e = self.re().exp();
+
+                    self.chain(e.clone(), e)
+                }
+
+                fn ln(&self) -> Self {
+                    self.chain(self.re().ln(), T::one() / self.re().clone())
+                }
+
+                fn sqrt(&self) -> Self {
+                    let s = self.re().sqrt();
+
+                    self.chain(s.clone(), T::one() / (s.clone() + s))
+                }
+
+                fn sin(&self) -> Self {
+                    self.chain(self.re().sin(), self.re().cos())
+                }
+
+                fn cos(&self) -> Self {
+                    self.chain(self.re().cos(), T::zero() - self.re().sin())
+                }
+
+                fn tan(&self) -> Self {
+                    let t = self.re().tan();
+
+                    self.chain(t.clone(), T::one() + t.clone() * t)
+                }
+
+                fn atan(&self) -> Self {
+                    let re = self.re().clone();
+
+                    self.chain(re.atan(), T::one() / (T::one() + re.clone() * re))
+                }
+
+                fn tanh(&self) -> Self {
+                    let t = self.re().tanh();
+
+                    self.chain(t.clone(), T::one() - t.clone() * t)
+                }
+
+                fn powi(&self, n: i32) -> Self {
+                    if n == 0 {
+                        return Self::one();
+                    }
+
+                    self.chain(self.re().powi(n), T::from_f64(n as f64) * self.re().powi(n - 1))
+                }
+
+                /// `re^n` with the derivative `n re^(n-1)` by the base and `re^n ln(re)` by the exponent.
+                /// The latter is left out for a constant exponent, so that negative bases work like in the float powf
+                fn powf(&self, n: &Self) -> Self {
+                    let pow = self.re().powf(n.re());
+                    let by_base = self.chain(pow.clone(), n.re().clone() * self.re().powf(&(n.re().clone() - T::one())));
+
+                    if *n == Self::constant(n.re().clone()) {
+                        return by_base;
+                    }
+
+                    by_base + n.chain(T::zero(), pow * self.re().ln())
+                }
+            }
+        )*
+    };
+}
+
+implDual!([] Dual<T>, [const N: usize] DualVec<T, N>);
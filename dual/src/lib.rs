@@ -0,0 +1,230 @@
+//! # dual
+//!
+//! Dual numbers for forward mode automatic differentiation: a function written against the tech traits
+//! and called with `Dual::variable(x)` returns its value and its derivative at x
+
+pub mod dl {
+    use tech::{Ring, UnRing, Meta, AssAdd, ComAdd, AssMul, ComMul, ApproxEq, Tolerance, MathError, split_sign};
+    use std::{
+        ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+        fmt::Display,
+    };
+
+    /// `re + du ε` with `ε^2 = 0`. Nested duals `Dual<Dual<T>>` are hyper-dual numbers
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Dual<T: Ring> {
+        re: T,
+        du: T,
+    }
+
+    /// `re + du1 ε1 + du2 ε2 + du12 ε1ε2`, gives second derivatives
+    pub type HyperDual<T> = Dual<Dual<T>>;
+
+    impl<T: Ring> Dual<T> {
+        pub fn new(re: T, du: T) -> Dual<T> {
+            Dual {re, du}
+        }
+
+        pub fn re(&self) -> &T {
+            &self.re
+        }
+
+        /// The derivative part
+        pub fn du(&self) -> &T {
+            &self.du
+        }
+    }
+
+    impl<T: UnRing> Dual<T> {
+        /// `x + ε`, the variable that functions are differentiated by
+        pub fn variable(x: T) -> Dual<T> {
+            Dual {re: x, du: T::one()}
+        }
+
+        /// `x + 0ε`, a value that does not depend on the variable
+        pub fn constant(x: T) -> Dual<T> {
+            Dual {re: x, du: T::zero()}
+        }
+    }
+
+    impl<T: UnRing + Div<Output = T> + Clone> Dual<T> {
+        /// `1/re - du/re^2 ε`, fails with NonInvertible if the real part is zero
+        pub fn inverse(&self) -> Result<Dual<T>, MathError> {
+            if self.re.is_zero() {
+                return Err(MathError::NonInvertible);
+            }
+
+            let inv = T::one() / self.re.clone();
+
+            Ok(Dual {re: inv.clone(), du: T::zero() - self.du.clone() * inv.clone() * inv})
+        }
+
+        /// `f(re) + f'(re) du ε` for the value and the derivative of f at re
+        pub(crate) fn chain(&self, value: T, derivative: T) -> Dual<T> {
+            Dual {re: value, du: derivative * self.du.clone()}
+        }
+    }
+
+    impl<T: Ring> From<T> for Dual<T> {
+        fn from(re: T) -> Self {
+            Dual {re, du: T::zero()}
+        }
+    }
+
+    impl<T: Ring + Clone> Add for &Dual<T> {
+        type Output = Dual<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Dual {re: self.re.clone() + rhs.re.clone(), du: self.du.clone() + rhs.du.clone()}
+        }
+    }
+
+    impl<T: Ring + Clone> Sub for &Dual<T> {
+        type Output = Dual<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Dual {re: self.re.clone() - rhs.re.clone(), du: self.du.clone() - rhs.du.clone()}
+        }
+    }
+
+    impl<T: Ring + Clone> Mul for &Dual<T> {
+        type Output = Dual<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            Dual {
+                re: self.re.clone() * rhs.re.clone(),
+                du: self.re.clone() * rhs.du.clone() + self.du.clone() * rhs.re.clone(),
+            }
+        }
+    }
+
+    /// Panics if the real part of rhs is zero, see inverse
+    impl<T: UnRing + Div<Output = T> + Clone> Div for &Dual<T> {
+        type Output = Dual<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            if rhs.re.is_zero() {
+                panic!("dividing by a Dual with a zero real part");
+            }
+
+            let denom = rhs.re.clone() * rhs.re.clone();
+
+            Dual {
+                re: self.re.clone() / rhs.re.clone(),
+                du: (self.du.clone() * rhs.re.clone() - self.re.clone() * rhs.du.clone()) / denom,
+            }
+        }
+    }
+
+    /// A macro for implementing the owned and assigning versions of an operator through the borrowed one
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl<T: Ring + Clone> $op for Dual<T> {
+                    type Output = Dual<T>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        (&self).$method(&rhs)
+                    }
+                }
+
+                impl<T: Ring + Clone> $op_assign for Dual<T> {
+                    fn $method_assign(&mut self, rhs: Self) {
+                        *self = (&*self).$method(&rhs);
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+    impl<T: UnRing + Div<Output = T> + Clone> Div for Dual<T> {
+        type Output = Dual<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            &self / &rhs
+        }
+    }
+
+    impl<T: UnRing + Div<Output = T> + Clone> DivAssign for Dual<T> {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = &*self / &rhs;
+        }
+    }
+
+    impl<T: Ring + Clone> Neg for Dual<T> {
+        type Output = Dual<T>;
+
+        fn neg(self) -> Self::Output {
+            -&self
+        }
+    }
+
+    impl<T: Ring + Clone> Neg for &Dual<T> {
+        type Output = Dual<T>;
+
+        fn neg(self) -> Self::Output {
+            Dual {re: T::zero() - self.re.clone(), du: T::zero() - self.du.clone()}
+        }
+    }
+
+    /// `(2 - 3ε)`, the sign of du is written as the operator
+    impl<T: Ring + Display> Display for Dual<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let (negative, du) = split_sign(&self.du);
+
+            write!(f, "({} {} {}ε)", self.re, if negative {'-'} else {'+'}, du)
+        }
+    }
+
+    //ε is a zero divisor, so Dual is not an IntegralDomain and can not be divided by every non-zero value
+    impl<T: Ring + Clone> AssAdd for Dual<T> {}
+    impl<T: Ring + Clone> ComAdd for Dual<T> {}
+    impl<T: Ring + AssMul + Clone> AssMul for Dual<T> {}
+    impl<T: Ring + ComMul + Clone> ComMul for Dual<T> {}
+
+    impl<T: Ring + Clone> Ring for Dual<T> {
+        fn zero() -> Self {
+            Dual {re: T::zero(), du: T::zero()}
+        }
+    }
+
+    impl<T: UnRing + Clone> UnRing for Dual<T> {
+        fn one() -> Self {
+            Dual::constant(T::one())
+        }
+    }
+
+    impl<T: UnRing + Meta + Clone> Meta for Dual<T> {
+        fn non_zero() -> Self {
+            Dual::one()
+        }
+
+        fn name() -> String {
+            format!("Dual<{}>", T::name())
+        }
+    }
+
+    impl<T: Ring + ApproxEq> ApproxEq for Dual<T> {
+        fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+            self.re.approx_eq(&other.re, tol) && self.du.approx_eq(&other.du, tol)
+        }
+    }
+}
+
+pub mod vector;
+pub mod elementary;
+pub mod diff;
+
+#[cfg(test)]
+mod tests {
+    use crate::{dl::{Dual, HyperDual}, vector::DualVec};
+
+    #[test]
+    fn display_subtracts_negative_infinitesimal_parts() {
+        assert_eq!(Dual::new(2.0, -3.0).to_string(), "(2 - 3ε)");
+        assert_eq!(DualVec::new(1.0, [0.5, -1.0]).to_string(), "(1 + 0.5ε0 - 1ε1)");
+        assert_eq!(HyperDual::new(Dual::new(1.0, 2.0), Dual::new(-1.0, 0.0)).to_string(), "((1 + 2ε) + (-1 + 0ε)ε)");
+    }
+}
@@ -0,0 +1,227 @@
+//! Dual numbers with N infinitesimal parts, one pass of a function gives its whole gradient
+
+use tech::{Ring, UnRing, Meta, AssAdd, ComAdd, AssMul, ComMul, ApproxEq, Tolerance, MathError, split_sign};
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+    fmt::Display,
+};
+
+/// `re + du[0] ε0 + ... + du[N-1] εN-1` with `εi εj = 0` for all i and j
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualVec<T: Ring, const N: usize> {
+    re: T,
+    du: [T; N],
+}
+
+impl<T: Ring, const N: usize> DualVec<T, N> {
+    pub fn new(re: T, du: [T; N]) -> DualVec<T, N> {
+        DualVec {re, du}
+    }
+
+    pub fn re(&self) -> &T {
+        &self.re
+    }
+
+    /// The partial derivatives
+    pub fn du(&self) -> &[T; N] {
+        &self.du
+    }
+}
+
+impl<T: UnRing, const N: usize> DualVec<T, N> {
+    pub fn constant(x: T) -> DualVec<T, N> {
+        DualVec {re: x, du: std::array::from_fn(|_| T::zero())}
+    }
+
+    /// `x[i] + εi` for every i, the variables that functions are differentiated by
+    pub fn variables(x: [T; N]) -> [DualVec<T, N>; N] {
+        let mut i = 0;
+
+        x.map(|re| {
+            let du = std::array::from_fn(|j| if i == j {T::one()} else {T::zero()});
+            i += 1;
+
+            DualVec {re, du}
+        })
+    }
+}
+
+impl<T: UnRing + Div<Output = T> + Clone, const N: usize> DualVec<T, N> {
+    /// Fails with NonInvertible if the real part is zero
+    pub fn inverse(&self) -> Result<DualVec<T, N>, MathError> {
+        if self.re.is_zero() {
+            return Err(MathError::NonInvertible);
+        }
+
+        let inv = T::one() / self.re.clone();
+
+        Ok(self.chain(inv.clone(), T::zero() - inv.clone() * inv))
+    }
+
+    /// `f(re) + f'(re) du ε` for the value and the derivative of f at re
+    pub(crate) fn chain(&self, value: T, derivative: T) -> DualVec<T, N> {
+        DualVec {re: value, du: self.du.clone().map(|d| derivative.clone() * d)}
+    }
+}
+
+impl<T: Ring, const N: usize> From<T> for DualVec<T, N> {
+    fn from(re: T) -> Self {
+        DualVec {re, du: std::array::from_fn(|_| T::zero())}
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Add for &DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        DualVec {
+            re: self.re.clone() + rhs.re.clone(),
+            du: std::array::from_fn(|i| self.du[i].clone() + rhs.du[i].clone()),
+        }
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Sub for &DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        DualVec {
+            re: self.re.clone() - rhs.re.clone(),
+            du: std::array::from_fn(|i| self.du[i].clone() - rhs.du[i].clone()),
+        }
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Mul for &DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut du: [T; N] = std::array::from_fn(|i| self.re.clone() * rhs.du[i].clone());
+
+        for (d, l) in du.iter_mut().zip(self.du.iter()) {
+            *d += l.clone() * rhs.re.clone();
+        }
+
+        DualVec {re: self.re.clone() * rhs.re.clone(), du}
+    }
+}
+
+/// Panics if the real part of rhs is zero, see inverse
+impl<T: UnRing + Div<Output = T> + Clone, const N: usize> Div for &DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.re.is_zero() {
+            panic!("dividing by a DualVec with a zero real part");
+        }
+
+        let denom = rhs.re.clone() * rhs.re.clone();
+
+        DualVec {
+            re: self.re.clone() / rhs.re.clone(),
+            du: std::array::from_fn(|i| {
+                (self.du[i].clone() * rhs.re.clone() - self.re.clone() * rhs.du[i].clone()) / denom.clone()
+            }),
+        }
+    }
+}
+
+/// A macro for implementing the owned and assigning versions of an operator through the borrowed one
+macro_rules! implOp {
+    ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+        $(
+            impl<T: Ring + Clone, const N: usize> $op for DualVec<T, N> {
+                type Output = DualVec<T, N>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<T: Ring + Clone, const N: usize> $op_assign for DualVec<T, N> {
+                fn $method_assign(&mut self, rhs: Self) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+impl<T: UnRing + Div<Output = T> + Clone, const N: usize> Div for DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<T: UnRing + Div<Output = T> + Clone, const N: usize> DivAssign for DualVec<T, N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = &*self / &rhs;
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Neg for DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Neg for &DualVec<T, N> {
+    type Output = DualVec<T, N>;
+
+    fn neg(self) -> Self::Output {
+        DualVec {re: T::zero() - self.re.clone(), du: self.du.clone().map(|d| T::zero() - d)}
+    }
+}
+
+impl<T: Ring + Display, const N: usize> Display for DualVec<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.re)?;
+
+        for (i, d) in self.du.iter().enumerate() {
+            let (negative, d) = split_sign(d);
+
+            write!(f, " {} {}ε{}", if negative {'-'} else {'+'}, d, i)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> AssAdd for DualVec<T, N> {}
+impl<T: Ring + Clone, const N: usize> ComAdd for DualVec<T, N> {}
+impl<T: Ring + AssMul + Clone, const N: usize> AssMul for DualVec<T, N> {}
+impl<T: Ring + ComMul + Clone, const N: usize> ComMul for DualVec<T, N> {}
+
+impl<T: Ring + Clone, const N: usize> Ring for DualVec<T, N> {
+    fn zero() -> Self {
+        DualVec::from(T::zero())
+    }
+}
+
+impl<T: UnRing + Clone, const N: usize> UnRing for DualVec<T, N> {
+    fn one() -> Self {
+        DualVec::constant(T::one())
+    }
+}
+
+impl<T: UnRing + Meta + Clone, const N: usize> Meta for DualVec<T, N> {
+    fn non_zero() -> Self {
+        DualVec::one()
+    }
+
+    fn name() -> String {
+        format!("DualVec<{}, {}>", T::name(), N)
+    }
+}
+
+impl<T: Ring + ApproxEq, const N: usize> ApproxEq for DualVec<T, N> {
+    fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+        self.re.approx_eq(&other.re, tol) && self.du.iter().zip(other.du.iter()).all(|(l, r)| l.approx_eq(r, tol))
+    }
+}
@@ -0,0 +1,321 @@
+//! Continued fractions: finite expansions of fractions, convergents and
+//! periodic expansions of quadratic irrationals
+
+use crate::fract::Fraction;
+use tech::{IntegralDomain, UnRing, Meta, OrderedRing, EuclidDiv};
+use std::fmt::Display;
+
+impl<T> Fraction<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone {
+    /// Partial quotients `[a0; a1, ..., an]` of the fraction.
+    /// Only a0 may be negative, the expansion never ends with 1 unless it is `[1]`
+    pub fn continued_fraction(&self) -> Vec<T> {
+        let (mut p, mut q) = if self.denom().is_negative() {
+            (T::zero() - self.num().clone(), T::zero() - self.denom().clone())
+        } else {
+            (self.num().clone(), self.denom().clone())
+        };
+
+        let mut res = Vec::new();
+
+        while !q.is_zero() {
+            let a = p.euclid_div(&q);
+            let r = p.euclid_rem(&q);
+
+            res.push(a);
+
+            p = q;
+            q = r;
+        }
+
+        res
+    }
+
+    /// Value of the finite continued fraction `[a0; a1, ..., an]`.
+    /// Returns None for an empty slice or if a zero denominator shows up on the way
+    pub fn from_continued_fraction(quotients: &[T]) -> Option<Fraction<T>> {
+        let (last, rest) = quotients.split_last()?;
+
+        let mut num = last.clone();
+        let mut denom = T::one();
+
+        for a in rest.iter().rev() {
+            if num.is_zero() {
+                return None;
+            }
+
+            let next = a.clone() * num.clone() + denom;
+
+            denom = num;
+            num = next;
+        }
+
+        Some(Fraction::new(num, denom))
+    }
+
+    pub fn convergents(&self) -> Convergents<std::vec::IntoIter<T>, T> {
+        convergents(self.continued_fraction())
+    }
+
+    pub fn semiconvergents(&self) -> Semiconvergents<std::vec::IntoIter<T>, T> {
+        semiconvergents(self.continued_fraction())
+    }
+}
+
+/// Iterator over the convergents `h_n/k_n` of a (possibly infinite) continued fraction
+#[derive(Debug, Clone)]
+pub struct Convergents<I, T> {
+    quotients: I,
+    prev: (T, T),
+    cur: (T, T),
+}
+
+pub fn convergents<I, T>(quotients: I) -> Convergents<I::IntoIter, T>
+where I: IntoIterator<Item = T>, T: IntegralDomain + UnRing + Meta + Clone {
+    Convergents {quotients: quotients.into_iter(), prev: (T::zero(), T::one()), cur: (T::one(), T::zero())}
+}
+
+impl<I, T> Iterator for Convergents<I, T>
+where I: Iterator<Item = T>, T: IntegralDomain + UnRing + Meta + Clone {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.quotients.next()?;
+
+        let h = a.clone() * self.cur.0.clone() + self.prev.0.clone();
+        let k = a * self.cur.1.clone() + self.prev.1.clone();
+
+        self.prev = std::mem::replace(&mut self.cur, (h.clone(), k.clone()));
+
+        Some(Fraction::new(h, k))
+    }
+}
+
+/// Iterator over the semiconvergents `(t h_{n-1} + h_{n-2})/(t k_{n-1} + k_{n-2})`, `1 <= t <= a_n`.
+/// The last semiconvergent for every n (`t = a_n`) is the convergent itself
+#[derive(Debug, Clone)]
+pub struct Semiconvergents<I, T> {
+    quotients: I,
+    prev: (T, T),
+    cur: (T, T),
+    //the current partial quotient and t
+    step: Option<(T, T)>,
+}
+
+pub fn semiconvergents<I, T>(quotients: I) -> Semiconvergents<I::IntoIter, T>
+where I: IntoIterator<Item = T>, T: IntegralDomain + UnRing + Meta + OrderedRing + Clone {
+    Semiconvergents {quotients: quotients.into_iter(), prev: (T::zero(), T::one()), cur: (T::one(), T::zero()), step: None}
+}
+
+impl<I, T> Iterator for Semiconvergents<I, T>
+where I: Iterator<Item = T>, T: IntegralDomain + UnRing + Meta + OrderedRing + Clone {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, t) = match self.step.take() {
+            Some(step) => step,
+            None => {
+                let a = self.quotients.next()?;
+
+                //a0 is the only quotient without intermediate fractions
+                let t = if self.cur.1.is_zero() {a.clone()} else {T::one()};
+
+                (a, t)
+            }
+        };
+
+        let h = t.clone() * self.cur.0.clone() + self.prev.0.clone();
+        let k = t.clone() * self.cur.1.clone() + self.prev.1.clone();
+
+        if t >= a {
+            self.prev = std::mem::replace(&mut self.cur, (h.clone(), k.clone()));
+        } else {
+            self.step = Some((a, t + T::one()));
+        }
+
+        Some(Fraction::new(h, k))
+    }
+}
+
+/// Largest integer whose square does not exceed n, n has to be non-negative
+fn isqrt<T>(n: &T) -> T
+where T: IntegralDomain + UnRing + OrderedRing + EuclidDiv + Clone {
+    if n.is_zero() {
+        return T::zero();
+    }
+
+    let two = T::one() + T::one();
+
+    let mut x = n.clone();
+    let mut y = (x.clone() + T::one()).euclid_div(&two);
+
+    while y < x {
+        x = y;
+        y = (x.clone() + n.euclid_div(&x)).euclid_div(&two);
+    }
+
+    x
+}
+
+/// The number `(p + √d)/q`
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadraticIrrational<T> {
+    p: T,
+    q: T,
+    d: T,
+}
+
+/// Continued fraction `[a0; a1, ..., ak, (b1, ..., bm)]` whose tail `b1, ..., bm` repeats forever.
+/// The period is empty for rational numbers
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodicContinuedFraction<T> {
+    pub pre_period: Vec<T>,
+    pub period: Vec<T>,
+}
+
+impl<T> QuadraticIrrational<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone {
+    /// Panics if q is zero or d is negative
+    pub fn new(p: T, q: T, d: T) -> QuadraticIrrational<T> {
+        if q.is_zero() {
+            panic!("Creating a QuadraticIrrational with zero denominator");
+        }
+
+        if d.is_negative() {
+            panic!("Creating a QuadraticIrrational from a negative number under the root");
+        }
+
+        QuadraticIrrational {p, q, d}
+    }
+
+    /// `√d`
+    pub fn sqrt(d: T) -> QuadraticIrrational<T> {
+        QuadraticIrrational::new(T::zero(), T::one(), d)
+    }
+
+    pub fn continued_fraction(&self) -> PeriodicContinuedFraction<T> {
+        let (mut p, mut q, mut d) = (self.p.clone(), self.q.clone(), self.d.clone());
+        let s = isqrt(&d);
+
+        if s.clone() * s.clone() == d {
+            let pre_period = Fraction::new(p + s, q).continued_fraction();
+
+            return PeriodicContinuedFraction {pre_period, period: Vec::new()};
+        }
+
+        //the recurrence needs q | d - p^2
+        if !(d.clone() - p.clone() * p.clone()).euclid_rem(&q).is_zero() {
+            let abs = q.abs();
+
+            p *= abs.clone();
+            d *= q.clone() * q.clone();
+            q *= abs;
+        }
+
+        let s = isqrt(&d);
+
+        let mut states: Vec<(T, T)> = Vec::new();
+        let mut quotients = Vec::new();
+
+        loop {
+            if let Some(start) = states.iter().position(|v| v.0 == p && v.1 == q) {
+                let period = quotients.split_off(start);
+
+                return PeriodicContinuedFraction {pre_period: quotients, period};
+            }
+
+            //floor((p + √d)/q), √d is irrational so (p + √d)/|q| is never an integer
+            let a = if q.is_positive() {
+                (p.clone() + s.clone()).euclid_div(&q)
+            } else {
+                T::zero() - (p.clone() + s.clone()).euclid_div(&q.abs()) - T::one()
+            };
+
+            states.push((p.clone(), q.clone()));
+
+            p = a.clone() * q.clone() - p;
+            q = (d.clone() - p.clone() * p.clone()).euclid_div(&q);
+
+            quotients.push(a);
+        }
+    }
+}
+
+impl<T> PeriodicContinuedFraction<T>
+where T: IntegralDomain + UnRing + Meta + Clone {
+    /// Infinite iterator over the partial quotients, finite if the period is empty
+    pub fn quotients(&self) -> impl Iterator<Item = T> + '_ {
+        self.pre_period.iter().cloned().chain(self.period.iter().cloned().cycle())
+    }
+
+    /// Convergents of the expansion, infinite unless the period is empty
+    pub fn convergents(&self) -> Convergents<impl Iterator<Item = T> + '_, T> {
+        convergents(self.quotients())
+    }
+}
+
+impl<T: Display> Display for PeriodicContinuedFraction<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut items: Vec<String> = self.pre_period.iter().map(|a| a.to_string()).collect();
+
+        if !self.period.is_empty() {
+            let period: Vec<String> = self.period.iter().map(|b| b.to_string()).collect();
+
+            items.push(format!("({})", period.join(", ")));
+        }
+
+        match items.split_first() {
+            None => write!(f, "[]"),
+            Some((first, [])) => write!(f, "[{}]", first),
+            Some((first, rest)) => write!(f, "[{}; {}]", first, rest.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_fractions() {
+        let val = Fraction::new(7i64, -3);
+
+        assert_eq!(val.continued_fraction(), vec![-3, 1, 2]);
+        assert_eq!(Fraction::new(-7i64, 3).continued_fraction(), vec![-3, 1, 2]);
+        assert_eq!(val.convergents().collect::<Vec<_>>(), vec![Fraction::new(-3, 1), Fraction::new(-2, 1), Fraction::new(-7, 3)]);
+        assert_eq!(Fraction::from_continued_fraction(&[-3i64, 1, 2]), Some(val));
+    }
+
+    #[test]
+    fn round_trip_and_invalid_expansions() {
+        let val = Fraction::new(415i64, 93);
+
+        assert_eq!(val.continued_fraction(), vec![4, 2, 6, 7]);
+        assert_eq!(Fraction::from_continued_fraction(&val.continued_fraction()), Some(val));
+        assert_eq!(Fraction::<i64>::from_continued_fraction(&[]), None);
+        assert_eq!(Fraction::from_continued_fraction(&[2i64, 0]), None);
+    }
+
+    #[test]
+    fn semiconvergents_end_with_the_convergent() {
+        let semi: Vec<_> = Fraction::new(415i64, 93).semiconvergents().collect();
+
+        assert_eq!(semi[..3], [Fraction::new(4, 1), Fraction::new(5, 1), Fraction::new(9, 2)]);
+        assert_eq!(semi.last(), Some(&Fraction::new(415, 93)));
+    }
+
+    #[test]
+    fn period_of_square_roots() {
+        let sqrt = |d: i64| QuadraticIrrational::sqrt(d).continued_fraction();
+
+        assert_eq!(sqrt(2), PeriodicContinuedFraction {pre_period: vec![1], period: vec![2]});
+        assert_eq!(sqrt(7).to_string(), "[2; (1, 1, 1, 4)]");
+        assert_eq!(sqrt(14).to_string(), "[3; (1, 2, 1, 6)]");
+        assert_eq!(sqrt(4), PeriodicContinuedFraction {pre_period: vec![2], period: vec![]});
+        assert_eq!(QuadraticIrrational::new(1i64, 2, 5).continued_fraction().to_string(), "[(1)]");
+        assert_eq!(QuadraticIrrational::new(0i64, -1, 2).continued_fraction().to_string(), "[-2; 1, 1, (2)]");
+
+        let convergents: Vec<_> = sqrt(2).convergents().take(4).collect();
+        assert_eq!(convergents, vec![Fraction::new(1, 1), Fraction::new(3, 2), Fraction::new(7, 5), Fraction::new(17, 12)]);
+    }
+}
@@ -0,0 +1,304 @@
+//! Positional expansions of fractions: rounded decimal strings and
+//! exact expansions with the repeating part in any base
+
+use crate::fract::Fraction;
+use tech::{IntegralDomain, UnRing, Meta, OrderedRing, EuclidDiv, Gcd};
+use std::{
+    fmt::Display,
+    ops::DivAssign,
+};
+
+/// How the last digit of `to_decimal_string` is rounded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero
+    Down,
+    /// Away from zero
+    Up,
+    /// Towards negative infinity
+    Floor,
+    /// Towards positive infinity
+    Ceiling,
+    /// To the nearest, ties away from zero
+    HalfUp,
+    /// To the nearest, ties towards zero
+    HalfDown,
+    /// To the nearest, ties to the even digit
+    HalfEven,
+}
+
+/// Expansion `integer.pre_period(period)` of a fraction in some base.
+/// Digits are stored most significant first, the period is empty for terminating expansions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion<T> {
+    pub negative: bool,
+    pub integer: Vec<T>,
+    pub pre_period: Vec<T>,
+    pub period: Vec<T>,
+}
+
+/// Digits `0..36` of T, used to print them as characters
+fn digit_table<T: UnRing + Clone>() -> Vec<T> {
+    let mut res = vec![T::zero()];
+
+    for i in 1..36 {
+        let next = res[i - 1].clone() + T::one();
+        res.push(next);
+    }
+
+    res
+}
+
+fn digit_char<T: UnRing>(digit: &T, table: &[T]) -> Option<char> {
+    table.iter().position(|v| v == digit).and_then(|i| std::char::from_digit(i as u32, 36))
+}
+
+impl<T: UnRing + Clone + Display> Display for Expansion<T> {
+    /// Digits above 35 (for bases greater than 36) are printed in brackets: `[40]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let table = digit_table::<T>();
+
+        let write_digits = |f: &mut std::fmt::Formatter<'_>, digits: &[T]| -> std::fmt::Result {
+            for d in digits {
+                match digit_char(d, &table) {
+                    Some(c) => write!(f, "{}", c)?,
+                    None => write!(f, "[{}]", d)?,
+                }
+            }
+
+            Ok(())
+        };
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write_digits(f, &self.integer)?;
+
+        if self.pre_period.is_empty() && self.period.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, ".")?;
+        write_digits(f, &self.pre_period)?;
+
+        if !self.period.is_empty() {
+            write!(f, "(")?;
+            write_digits(f, &self.period)?;
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Fraction<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone {
+    /// Absolute value of num and denom and whether the fraction is negative
+    fn unsigned_parts(&self) -> (T, T, bool) {
+        (self.num().abs(), self.denom().abs(), self.num().is_negative() != self.denom().is_negative())
+    }
+
+    /// The exact expansion in base 10, for example `1/6 = 0.1(6)`
+    pub fn decimal_expansion(&self) -> Expansion<T> {
+        let ten = digit_table::<T>()[10].clone();
+
+        self.expansion(&ten)
+    }
+
+    /// The exact expansion in the given base. The period may be as long as the denominator.
+    /// Panics if the base is less than 2
+    pub fn expansion(&self, base: &T) -> Expansion<T> {
+        if *base <= T::one() {
+            panic!("Expanding a fraction in a base less than 2");
+        }
+
+        let (num, mut denom, negative) = self.unsigned_parts();
+
+        let mut int = num.euclid_div(&denom);
+        let mut rem = num.euclid_rem(&denom);
+
+        let mut integer = Vec::new();
+
+        while !int.is_zero() {
+            integer.push(int.euclid_rem(base));
+            int = int.euclid_div(base);
+        }
+
+        if integer.is_empty() {
+            integer.push(T::zero());
+        }
+
+        integer.reverse();
+
+        let negative = negative && !(num.is_zero());
+
+        if rem.is_zero() {
+            return Expansion {negative, integer, pre_period: Vec::new(), period: Vec::new()};
+        }
+
+        let gcd = rem.gcd(&denom);
+        rem /= gcd.clone();
+        denom /= gcd;
+
+        //the pre-period is as long as it takes to remove the prime factors shared with the base from the denominator
+        let mut pre_len = 0;
+        let mut rest = denom.clone();
+
+        loop {
+            let gcd = rest.gcd(base);
+
+            if gcd.is_one() {
+                break;
+            }
+
+            rest /= gcd;
+            pre_len += 1;
+        }
+
+        let next_digit = |rem: &mut T| {
+            let shifted = rem.clone() * base.clone();
+
+            *rem = shifted.euclid_rem(&denom);
+            shifted.euclid_div(&denom)
+        };
+
+        let pre_period: Vec<T> = (0..pre_len).map(|_| next_digit(&mut rem)).collect();
+
+        let mut period = Vec::new();
+
+        if !rem.is_zero() {
+            let start = rem.clone();
+
+            loop {
+                period.push(next_digit(&mut rem));
+
+                if rem == start {
+                    break;
+                }
+            }
+        }
+
+        Expansion {negative, integer, pre_period, period}
+    }
+}
+
+impl<T> Fraction<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone + Display {
+    /// The value with exactly `digits` digits after the decimal point
+    pub fn to_decimal_string(&self, digits: usize, mode: Rounding) -> String {
+        let table = digit_table::<T>();
+        let ten = table[10].clone();
+
+        let (num, denom, negative) = self.unsigned_parts();
+
+        let mut int = num.euclid_div(&denom);
+        let mut rem = num.euclid_rem(&denom);
+
+        let mut fract = Vec::with_capacity(digits);
+
+        for _ in 0..digits {
+            let shifted = rem.clone() * ten.clone();
+
+            rem = shifted.euclid_rem(&denom);
+            fract.push(table.iter().position(|v| *v == shifted.euclid_div(&denom)).expect("decimal digit out of range"));
+        }
+
+        let last_odd = match fract.last() {
+            Some(d) => d % 2 == 1,
+            None => !int.euclid_rem(&table[2]).is_zero(),
+        };
+
+        let exact = rem.is_zero();
+        let half = denom - rem.clone();
+
+        let round_up = match mode {
+            Rounding::Down => false,
+            Rounding::Up => !exact,
+            Rounding::Floor => negative && !exact,
+            Rounding::Ceiling => !negative && !exact,
+            Rounding::HalfUp => rem >= half,
+            Rounding::HalfDown => rem > half,
+            Rounding::HalfEven => rem > half || (rem == half && last_odd),
+        };
+
+        if round_up {
+            let mut carry = true;
+
+            for d in fract.iter_mut().rev() {
+                if *d == 9 {
+                    *d = 0;
+                } else {
+                    *d += 1;
+                    carry = false;
+
+                    break;
+                }
+            }
+
+            if carry {
+                int += T::one();
+            }
+        }
+
+        let is_zero = int.is_zero() && fract.iter().all(|d| *d == 0);
+
+        let mut res = String::new();
+
+        if negative && !is_zero {
+            res.push('-');
+        }
+
+        res.push_str(&int.to_string());
+
+        if digits > 0 {
+            res.push('.');
+            res.extend(fract.iter().map(|d| std::char::from_digit(*d as u32, 10).expect("decimal digit out of range")));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_rounding_mode() {
+        let modes = [Rounding::Down, Rounding::Up, Rounding::Floor, Rounding::Ceiling, Rounding::HalfUp, Rounding::HalfDown, Rounding::HalfEven];
+        let cases = [
+            ((5i64, 4), 1, ["1.2", "1.3", "1.2", "1.3", "1.3", "1.2", "1.2"]),
+            ((5, -4), 1, ["-1.2", "-1.3", "-1.3", "-1.2", "-1.3", "-1.2", "-1.2"]),
+            ((27, 20), 1, ["1.3", "1.4", "1.3", "1.4", "1.4", "1.3", "1.4"]),
+            ((2, 3), 2, ["0.66", "0.67", "0.66", "0.67", "0.67", "0.67", "0.67"]),
+            ((-1, 1000), 2, ["0.00", "-0.01", "-0.01", "0.00", "0.00", "0.00", "0.00"]),
+            ((999, 1000), 2, ["0.99", "1.00", "0.99", "1.00", "1.00", "1.00", "1.00"]),
+            ((5, 2), 0, ["2", "3", "2", "3", "3", "2", "2"]),
+            ((7, 2), 0, ["3", "4", "3", "4", "4", "3", "4"]),
+        ];
+
+        for ((num, denom), digits, expected) in cases {
+            let val = Fraction::new(num, denom);
+
+            for (mode, res) in modes.iter().zip(expected) {
+                assert_eq!(val.to_decimal_string(digits, *mode), res, "{} rounded {:?}", val, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn period_of_the_expansion() {
+        assert_eq!(Fraction::new(1i64, 6).decimal_expansion().to_string(), "0.1(6)");
+        assert_eq!(Fraction::new(22i64, 7).decimal_expansion().to_string(), "3.(142857)");
+        assert_eq!(Fraction::new(-1i64, 12).decimal_expansion().to_string(), "-0.08(3)");
+        assert_eq!(Fraction::new(-5i64, -8).decimal_expansion().to_string(), "0.625");
+        assert_eq!(Fraction::new(0i64, -3).decimal_expansion().to_string(), "0");
+        assert_eq!(Fraction::new(1i64, 97).decimal_expansion().period.len(), 96);
+
+        assert_eq!(Fraction::new(1i64, 3).expansion(&3).to_string(), "0.1");
+        assert_eq!(Fraction::new(1i64, 6).expansion(&2).to_string(), "0.0(01)");
+        assert_eq!(Fraction::new(255i64, 16).expansion(&16).to_string(), "f.f");
+        assert_eq!(Fraction::new(1i64, 41).expansion(&40).to_string(), "0.(0[39])");
+    }
+}
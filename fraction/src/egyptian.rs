@@ -0,0 +1,163 @@
+//! Egyptian fractions: sums of distinct unit fractions `1/d1 + 1/d2 + ...`
+
+use crate::fract::{Fraction, Reducible, Policy};
+use tech::{IntegralDomain, UnRing, Meta, OrderedRing, EuclidDiv, Gcd};
+use std::ops::DivAssign;
+
+/// `ceil(a/b)` for positive a and b
+fn ceil_div<T>(a: &T, b: &T) -> T
+where T: IntegralDomain + UnRing + EuclidDiv + Clone {
+    (a.clone() + b.clone() - T::one()).euclid_div(b)
+}
+
+/// `p/q - 1/d` reduced
+fn subtract_unit<T>(p: &T, q: &T, d: &T) -> (T, T)
+where T: IntegralDomain + Gcd + DivAssign<T> + Clone {
+    let mut p = p.clone() * d.clone() - q.clone();
+    let mut q = q.clone() * d.clone();
+    let gcd = p.gcd(&q);
+
+    p /= gcd.clone();
+    q /= gcd;
+
+    (p, q)
+}
+
+/// Looks for exactly k distinct denominators not less than min_d that sum up to p/q,
+/// keeping the expansion with the smallest largest denominator in best
+fn search<T>(p: &T, q: &T, k: usize, min_d: T, cur: &mut Vec<T>, best: &mut Option<Vec<T>>)
+where T: IntegralDomain + UnRing + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone {
+    let better = |d: &T, best: &Option<Vec<T>>| best.as_ref().and_then(|b| b.last()).is_none_or(|last| d < last);
+
+    if k == 1 {
+        if q.euclid_rem(p).is_zero() {
+            let d = q.euclid_div(p);
+
+            if d >= min_d && better(&d, best) {
+                cur.push(d);
+                *best = Some(cur.clone());
+                cur.pop();
+            }
+        }
+
+        return;
+    }
+
+    let k_times_q = (0..k).fold(T::zero(), |acc, _| acc + q.clone());
+
+    let lower = ceil_div(q, p);
+    let mut d = if lower > min_d {lower} else {min_d};
+
+    //the largest term is at least 1/d, so k terms cover at most k/d
+    while d.clone() * p.clone() <= k_times_q && better(&d, best) {
+        let (np, nq) = subtract_unit(p, q, &d);
+
+        //a shorter expansion exists and has already been checked
+        if !np.is_zero() {
+            cur.push(d.clone());
+            search(&np, &nq, k - 1, d.clone() + T::one(), cur, best);
+            cur.pop();
+        }
+
+        d += T::one();
+    }
+}
+
+impl<T> Fraction<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone {
+    /// Reduced num and denom with a positive denom, if `0 < self < 1`
+    fn proper_parts(&self) -> Option<(T, T)> {
+        let fract = self.clone().canonicalized();
+        let (p, q) = (fract.num().clone(), fract.denom().clone());
+
+        if p.is_positive() && p < q {
+            Some((p, q))
+        } else {
+            None
+        }
+    }
+
+    /// Denominators of the greedy (Fibonacci–Sylvester) expansion, which always takes the largest unit fraction that fits.
+    /// Returns None unless `0 < self < 1`. The denominators may grow doubly exponentially and overflow T
+    pub fn egyptian_greedy(&self) -> Option<Vec<T>> {
+        let (mut p, mut q) = self.proper_parts()?;
+        let mut res = Vec::new();
+
+        while !p.is_zero() {
+            let d = ceil_div(&q, &p);
+
+            (p, q) = subtract_unit(&p, &q, &d);
+            res.push(d);
+        }
+
+        Some(res)
+    }
+
+    /// Denominators of an expansion with the fewest terms, among them the one with the smallest largest denominator.
+    /// Returns None unless `0 < self < 1`. The search is exponential in the number of terms
+    pub fn egyptian_shortest(&self) -> Option<Vec<T>> {
+        let (p, q) = self.proper_parts()?;
+        let greedy = self.egyptian_greedy()?;
+
+        //the greedy expansion bounds the length, but may have larger denominators than needed
+        for k in 1..=greedy.len() {
+            let mut best = None;
+
+            search(&p, &q, k, T::one(), &mut Vec::with_capacity(k), &mut best);
+
+            if best.is_some() {
+                return best;
+            }
+        }
+
+        Some(greedy)
+    }
+}
+
+impl<T, P> Reducible<T, P>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+    pub fn egyptian_greedy(&self) -> Option<Vec<T>> {
+        Fraction::new(self.num().clone(), self.denom().clone()).egyptian_greedy()
+    }
+
+    pub fn egyptian_shortest(&self) -> Option<Vec<T>> {
+        Fraction::new(self.num().clone(), self.denom().clone()).egyptian_shortest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum(denoms: &[i64]) -> Fraction<i64> {
+        denoms.iter().fold(Fraction::new(0, 1), |acc, d| acc + Fraction::new(1, *d))
+    }
+
+    #[test]
+    fn shortest_versus_greedy() {
+        let val = Fraction::new(8i64, 11);
+        let greedy = val.egyptian_greedy().unwrap();
+        let shortest = val.egyptian_shortest().unwrap();
+
+        //as many terms, but smaller denominators
+        assert_eq!(greedy, vec![2, 5, 37, 4070]);
+        assert_eq!(shortest, vec![2, 6, 22, 66]);
+        assert_eq!(sum(&greedy), val);
+        assert_eq!(sum(&shortest), val);
+
+        assert_eq!(Fraction::new(7i64, 15).egyptian_greedy(), Some(vec![3, 8, 120]));
+        assert_eq!(Fraction::new(7i64, 15).egyptian_shortest(), Some(vec![5, 6, 10]));
+        assert_eq!(Fraction::new(-2i64, -3).egyptian_shortest(), Some(vec![2, 6]));
+        assert_eq!(Fraction::new(2i64, 4).egyptian_shortest(), Some(vec![2]));
+    }
+
+    #[test]
+    fn none_outside_the_unit_interval() {
+        for (num, denom) in [(0i64, 1), (-1, 2), (1, -2), (1, 1), (3, 2), (-3, -3)] {
+            assert_eq!(Fraction::new(num, denom).egyptian_greedy(), None);
+            assert_eq!(Fraction::new(num, denom).egyptian_shortest(), None);
+        }
+
+        assert_eq!(Reducible::new(1i64, 1).egyptian_shortest(), None);
+    }
+}
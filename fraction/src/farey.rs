@@ -0,0 +1,175 @@
+//! Farey sequences, mediants and the Stern–Brocot tree
+
+use crate::fract::{Fraction, Reducible, Policy};
+use tech::{IntegralDomain, UnRing, Meta, OrderedRing, EuclidDiv, Gcd};
+use std::ops::DivAssign;
+
+/// A step down the Stern–Brocot tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Branch {
+    /// Towards smaller fractions
+    Left,
+    /// Towards larger fractions
+    Right,
+}
+
+impl<T> Fraction<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone {
+    /// `(a + c)/(b + d)` for `a/b` and `c/d`, the signs are moved to the numerators first
+    pub fn mediant(&self, rhs: &Fraction<T>) -> Fraction<T> {
+        let mut l = self.clone();
+        let mut r = rhs.clone();
+
+        l.normalize_sign();
+        r.normalize_sign();
+
+        Fraction::new(l.num().clone() + r.num().clone(), l.denom().clone() + r.denom().clone())
+    }
+
+    /// Steps from the root `1/1` of the Stern–Brocot tree down to the fraction.
+    /// Returns None unless the fraction is positive, `1/1` itself has an empty path
+    pub fn stern_brocot_path(&self) -> Option<Vec<Branch>> {
+        if self.num().is_zero() || self.num().is_positive() != self.denom().is_positive() {
+            return None;
+        }
+
+        //[a0; a1, ..., an] is R^a0 L^a1 R^a2 ... with the last exponent decreased by one
+        let quotients = self.continued_fraction();
+        let mut res = Vec::new();
+
+        for (i, a) in quotients.iter().enumerate() {
+            let branch = if i % 2 == 0 {Branch::Right} else {Branch::Left};
+            let mut count = if i + 1 == quotients.len() {a.clone() - T::one()} else {a.clone()};
+
+            while count.is_positive() {
+                res.push(branch);
+                count -= T::one();
+            }
+        }
+
+        Some(res)
+    }
+
+    /// The fraction at the end of the path from the root `1/1`
+    pub fn from_stern_brocot_path(path: &[Branch]) -> Fraction<T> {
+        //the fraction lies between the bounds l and r, starting with 0/1 and 1/0
+        let (mut l, mut r) = ((T::zero(), T::one()), (T::one(), T::zero()));
+
+        for branch in path {
+            let mediant = (l.0.clone() + r.0.clone(), l.1.clone() + r.1.clone());
+
+            match branch {
+                Branch::Left => r = mediant,
+                Branch::Right => l = mediant,
+            }
+        }
+
+        Fraction::new(l.0 + r.0, l.1 + r.1)
+    }
+}
+
+/// Iterator over the Farey sequence of order n: reduced fractions from `0/1` to `1/1` with denominators up to n, in increasing order
+#[derive(Debug, Clone)]
+pub struct Farey<T> {
+    n: T,
+    //the next fraction a/b and the one after it c/d
+    next: Option<(T, T, T, T)>,
+}
+
+/// Panics if n is less than one
+pub fn farey<T>(n: T) -> Farey<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone {
+    if n < T::one() {
+        panic!("Farey sequence of an order less than one");
+    }
+
+    let next = Some((T::zero(), T::one(), T::one(), n.clone()));
+
+    Farey {n, next}
+}
+
+impl<T> Iterator for Farey<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, b, c, d) = self.next.take()?;
+
+        if a != b {
+            let k = (self.n.clone() + b.clone()).euclid_div(&d);
+
+            self.next = Some((c.clone(), d.clone(), k.clone() * c - a.clone(), k * d - b.clone()));
+        }
+
+        Some(Fraction::new(a, b))
+    }
+}
+
+impl<T, P> Reducible<T, P>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+    fn to_fraction(&self) -> Fraction<T> {
+        Fraction::new(self.num().clone(), self.denom().clone())
+    }
+
+    pub fn mediant(&self, rhs: &Reducible<T, P>) -> Reducible<T, P> {
+        let res = self.to_fraction().mediant(&rhs.to_fraction());
+
+        Reducible::with_policy(res.num().clone(), res.denom().clone())
+    }
+
+    pub fn stern_brocot_path(&self) -> Option<Vec<Branch>> {
+        self.to_fraction().stern_brocot_path()
+    }
+
+    pub fn from_stern_brocot_path(path: &[Branch]) -> Reducible<T, P> {
+        let res = Fraction::<T>::from_stern_brocot_path(path);
+
+        Reducible::with_policy(res.num().clone(), res.denom().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Branch::{Left, Right};
+
+    #[test]
+    fn farey_sequence_of_order_n() {
+        let parts: Vec<_> = farey(5i64).map(|v| (*v.num(), *v.denom())).collect();
+
+        assert_eq!(parts, vec![(0, 1), (1, 5), (1, 4), (1, 3), (2, 5), (1, 2), (3, 5), (2, 3), (3, 4), (4, 5), (1, 1)]);
+        assert_eq!(farey(1i64).count(), 2);
+        assert_eq!(farey(10i64).count(), 33);
+    }
+
+    #[test]
+    fn stern_brocot_round_trip() {
+        assert_eq!(Fraction::new(3i64, 5).stern_brocot_path(), Some(vec![Left, Right, Left]));
+        assert_eq!(Fraction::new(-4i64, -2).stern_brocot_path(), Some(vec![Right]));
+        assert_eq!(Fraction::new(1i64, 1).stern_brocot_path(), Some(vec![]));
+        assert_eq!(Fraction::new(0i64, 1).stern_brocot_path(), None);
+        assert_eq!(Fraction::new(1i64, -3).stern_brocot_path(), None);
+
+        for num in 1..12i64 {
+            for denom in 1..12 {
+                let val = Fraction::new(num, denom);
+                let back = Fraction::<i64>::from_stern_brocot_path(&val.stern_brocot_path().unwrap());
+
+                assert_eq!(back, val);
+                assert!(back.num().gcd(back.denom()) == 1);
+            }
+        }
+
+        let val: Reducible<i64> = Reducible::from_stern_brocot_path(&[Right, Right, Left]);
+        assert_eq!((*val.num(), *val.denom()), (5, 2));
+    }
+
+    #[test]
+    fn mediant_with_the_signs_on_num() {
+        let med = Fraction::new(1i64, 2).mediant(&Fraction::new(2, 3));
+        assert_eq!((*med.num(), *med.denom()), (3, 5));
+
+        let med = Fraction::new(1i64, -2).mediant(&Fraction::new(1, 3));
+        assert_eq!((*med.num(), *med.denom()), (0, 5));
+    }
+}
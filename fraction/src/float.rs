@@ -0,0 +1,252 @@
+//! Conversions between fractions of primitive integers and floating point numbers
+
+use crate::fract::Fraction;
+use tech::{IntegralDomain, Meta};
+
+/// Splits a finite non-zero f64 into an odd mantissa and an exponent, `|x| = m * 2^e`
+fn decompose(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let frac = bits & ((1 << 52) - 1);
+
+    let (mut m, mut e) = if exp == 0 {
+        (frac, -1074)
+    } else {
+        (frac | (1 << 52), exp - 1075)
+    };
+
+    let tz = m.trailing_zeros();
+    m >>= tz;
+    e += tz as i32;
+
+    (m, e)
+}
+
+/// The exact value of a finite f64 as `num/denom`, if it fits into i128
+fn exact(x: f64) -> Option<(i128, i128)> {
+    if !x.is_finite() {
+        return None;
+    }
+
+    if x == 0.0 {
+        return Some((0, 1));
+    }
+
+    let (m, e) = decompose(x);
+    let sign = if x < 0.0 {-1} else {1};
+
+    if e >= 0 {
+        if 64 - m.leading_zeros() as i32 + e > 127 {
+            return None;
+        }
+
+        Some((sign * ((m as i128) << e), 1))
+    } else if -e <= 126 {
+        Some((sign * m as i128, 1 << -e))
+    } else {
+        None
+    }
+}
+
+/// `n/d` rounded to nearest-even on the grid of a float with `precision` significant bits
+/// whose smallest subnormal is `2^min_exp`. The result is exact in f64
+fn round_ratio(n: u128, d: u128, precision: i32, min_exp: i32) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+
+    let bit_len = |v: u128| 128 - v.leading_zeros() as i32;
+
+    //2^e <= n/d < 2^(e+1)
+    let mut e = bit_len(n) - bit_len(d);
+    let below = if e >= 0 {n < d << e} else {n << -e < d};
+
+    if below {
+        e -= 1;
+    }
+
+    let lsb = (e - precision + 1).max(min_exp);
+
+    let mut q = n / d;
+    let mut r = n % d;
+
+    let round_up = if lsb <= 0 {
+        for _ in 0..-lsb {
+            q <<= 1;
+
+            //r < d <= 2^127, so doubling can not overflow
+            if r >= d - r {
+                q += 1;
+                r -= d - r;
+            } else {
+                r <<= 1;
+            }
+        }
+
+        r > d - r || (r == d - r && q & 1 == 1)
+    } else {
+        let k = lsb as u32;
+        let low = q & ((1 << k) - 1);
+        let half = 1 << (k - 1);
+
+        q >>= k;
+
+        low > half || (low == half && (r != 0 || q & 1 == 1))
+    };
+
+    if round_up {
+        q += 1;
+    }
+
+    q as f64 * 2f64.powi(lsb)
+}
+
+impl<T: IntegralDomain + Meta + Copy + Into<i128> + TryFrom<i128>> Fraction<T> {
+    /// The exact value of x. Returns None if x is not finite or does not fit into T
+    pub fn from_f64(x: f64) -> Option<Fraction<T>> {
+        let (num, denom) = exact(x)?;
+
+        Some(Fraction::new(T::try_from(num).ok()?, T::try_from(denom).ok()?))
+    }
+
+    pub fn from_f32(x: f32) -> Option<Fraction<T>> {
+        Fraction::from_f64(x as f64)
+    }
+
+    /// The closest f64 to the fraction, ties are rounded to even
+    pub fn to_f64(&self) -> f64 {
+        self.to_float(53, -1074)
+    }
+
+    /// The closest f32 to the fraction, ties are rounded to even
+    pub fn to_f32(&self) -> f32 {
+        self.to_float(24, -149) as f32
+    }
+
+    fn to_float(&self, precision: i32, min_exp: i32) -> f64 {
+        let num: i128 = (*self.num()).into();
+        let denom: i128 = (*self.denom()).into();
+
+        let abs = round_ratio(num.unsigned_abs(), denom.unsigned_abs(), precision, min_exp);
+
+        if (num < 0) != (denom < 0) {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// The best rational approximation of x with a denominator not greater than max_denominator:
+    /// no fraction with a denominator in this range is closer to x.
+    /// Returns None if x is not finite, max_denominator is not positive or the result does not fit into T
+    pub fn approximate(x: f64, max_denominator: T) -> Option<Fraction<T>> {
+        let max: i128 = max_denominator.into();
+
+        if !x.is_finite() || max < 1 {
+            return None;
+        }
+
+        let (p, q) = match exact(x.abs()) {
+            Some(v) => v,
+            //too small for an exact i128 fraction, so the rounding error is below 2^-127
+            None if x.abs() < 1.0 => (((x.abs() * 2f64.powi(126)).round()) as i128, 1 << 126),
+            None => return None,
+        };
+
+        let (num, denom) = best_approximation(p, q, max);
+        let num = if x < 0.0 {-num} else {num};
+
+        Some(Fraction::new(T::try_from(num).ok()?, T::try_from(denom).ok()?))
+    }
+}
+
+/// Best approximation of `p/q >= 0` with the denominator not greater than max
+fn best_approximation(p: i128, q: i128, max: i128) -> (i128, i128) {
+    //h/k are the convergents, the pair (h0, k0) precedes (h1, k1)
+    let (mut h0, mut k0, mut h1, mut k1) = (0i128, 1i128, 1i128, 0i128);
+    let (mut rest_p, mut rest_q) = (p, q);
+
+    while rest_q != 0 {
+        let a = rest_p / rest_q;
+
+        let next = a.checked_mul(h1).and_then(|v| v.checked_add(h0))
+            .zip(a.checked_mul(k1).and_then(|v| v.checked_add(k0)));
+
+        let (h2, k2) = match next {
+            Some((h2, k2)) if k2 <= max => (h2, k2),
+            _ => {
+                //the largest semiconvergent that still fits, t < a since the full step did not fit or overflowed
+                let t = ((max - k0) / k1).min(a - 1);
+                let semi = t.checked_mul(h1).and_then(|v| v.checked_add(h0))
+                    .zip(t.checked_mul(k1).and_then(|v| v.checked_add(k0)));
+
+                let semi = match semi {
+                    Some(semi) if t > 0 => semi,
+                    _ => return (h1, k1),
+                };
+
+                //2t compared to a, without computing 2t
+                let semi_better = if t != a - t {
+                    t > a - t
+                } else {
+                    closer((p, q), semi, (h1, k1))
+                };
+
+                return if semi_better {semi} else {(h1, k1)};
+            }
+        };
+
+        (h0, k0, h1, k1) = (h1, k1, h2, k2);
+        (rest_p, rest_q) = (rest_q, rest_p - a * rest_q);
+    }
+
+    (h1, k1)
+}
+
+/// Whether `a` is strictly closer than `b` to `x`
+fn closer(x: (i128, i128), a: (i128, i128), b: (i128, i128)) -> bool {
+    //|x - h/k| = |x.0 * k - h * x.1| / (x.1 * k)
+    let dist = |(h, k): (i128, i128)| x.0.checked_mul(k)?.checked_sub(h.checked_mul(x.1)?).map(|v| v.abs());
+
+    let exact = dist(a).zip(dist(b)).and_then(|(da, db)| Some(da.checked_mul(b.1)? < db.checked_mul(a.1)?));
+
+    exact.unwrap_or_else(|| {
+        let val = x.0 as f64 / x.1 as f64;
+
+        (val - a.0 as f64 / a.1 as f64).abs() < (val - b.0 as f64 / b.1 as f64).abs()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fract::Fraction;
+
+    fn parts(f: Option<Fraction<i128>>) -> Option<(i128, i128)> {
+        f.map(|f| (*f.num(), *f.denom()))
+    }
+
+    #[test]
+    fn approximate_small_bounds() {
+        assert_eq!(parts(Fraction::approximate(std::f64::consts::PI, 1000)), Some((355, 113)));
+        assert_eq!(parts(Fraction::approximate(-0.3333, 10)), Some((-1, 3)));
+        assert_eq!(parts(Fraction::approximate(0.75, 1)), Some((1, 1)));
+    }
+
+    #[test]
+    fn approximate_bounds_near_max() {
+        for x in [std::f64::consts::PI, 1e-30, 1.0 / 3.0, 12345.678, 3e20] {
+            for max in [i128::MAX, i128::MAX - 1, (i64::MAX as i128) * 3, 1 << 100] {
+                let res = Fraction::<i128>::approximate(x, max).expect("fits into i128");
+
+                assert!(*res.denom() <= max);
+                //a neighbour with a denominator at most max is within 1/max, tiny x are rounded to 2^-126
+                assert!((res.to_f64() - x).abs() <= (x * 1e-15).max(1.0 / max as f64) + 2f64.powi(-126));
+            }
+        }
+
+        assert_eq!(parts(Fraction::approximate(f64::MIN_POSITIVE, i128::MAX)), Some((0, 1)));
+
+        let res = Fraction::<i64>::approximate(std::f64::consts::E, i64::MAX).unwrap();
+        assert_eq!(res.to_f64(), std::f64::consts::E);
+    }
+}
@@ -1,15 +1,14 @@
-//TODO
-//create the Rational struct
-//commit
-
-
 #[macro_use]
 pub mod fract {
-    use tech::{IntegralDomain, Meta, AssAdd, AssMul, ComAdd, ComMul, Ring, UnRing, Field, Gcd};
+    use tech::{IntegralDomain, Meta, AssAdd, AssMul, ComAdd, ComMul, Ring, UnRing, DivisionRing, Field, Gcd, Bits, OrderedRing, EuclidDiv, Checked, MathError};
+    use imaginary::imgn::{Imaginary, ToImaginary};
 
     use std::{
         ops::{Mul, MulAssign, Add, AddAssign, Sub, SubAssign, Div, DivAssign, Neg},
         fmt::Display,
+        hash::{Hash, Hasher},
+        cmp::Ordering,
+        marker::PhantomData,
     };
 
     #[macro_export]
@@ -47,6 +46,7 @@ pub mod fract {
         }
     }
 
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
     impl<T: IntegralDomain + Meta> Div for Fraction<T> {
         type Output = Fraction<T>;
 
@@ -55,13 +55,14 @@ pub mod fract {
         }
     }
 
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
     impl<T: IntegralDomain + Meta> DivAssign for Fraction<T> {
         fn div_assign(&mut self, rhs: Self) {
             self.num *= rhs.denom;
             self.denom *= rhs.num;
 
             if self.denom.is_zero() {
-                panic!("Crearing a fraction with zero denom");
+                panic!("Creating a Fraction with zero denom");
             }
         }
     }
@@ -108,6 +109,14 @@ pub mod fract {
         }
     }
 
+    impl<T: IntegralDomain + Meta + Clone> Neg for &Fraction<T> {
+        type Output = Fraction<T>;
+
+        fn neg(self) -> Self::Output {
+            -self.clone()
+        }
+    }
+
     impl<T: IntegralDomain + Meta> Add for &Fraction<T>
     where for <'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Fraction<T>;
@@ -135,19 +144,26 @@ pub mod fract {
         }
     }
 
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
     impl<T: IntegralDomain + Meta> Div for &Fraction<T>
     where for <'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Fraction<T>;
     
         fn div(self, rhs: Self) -> Self::Output {
-            Fraction {num: &self.num * &rhs.denom, denom: &self.denom * &rhs.num}
+            fract!(&self.num * &rhs.denom, &self.denom * &rhs.num)
         }
     }
 
     macro_rules! implTrait {
         (for Reducible : $($i: ident),*) => {
             $(
-                impl<T: IntegralDomain + Meta + Clone + DivAssign<T> + Gcd> $i for Reducible<T> where for <'a> &'a T: Mul<&'a T, Output = T> {}
+                impl<T: IntegralDomain + Meta + Clone + DivAssign<T> + Gcd, P: Policy<T>> $i for Reducible<T, P> where for <'a> &'a T: Mul<&'a T, Output = T> {}
+            )*
+        };
+
+        (for Rational : $($i: ident),*) => {
+            $(
+                impl<T: IntegralDomain + Meta + PartialOrd + Clone + DivAssign<T> + Gcd> $i for Rational<T> where for <'a> &'a T: Mul<&'a T, Output = T> {}
             )*
         };
 
@@ -158,7 +174,7 @@ pub mod fract {
         };
     }
 
-    implTrait!(ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, Field);
+    implTrait!(ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, DivisionRing, Field);
 
     impl<T: IntegralDomain + Meta + Clone> Ring for Fraction<T> 
     where for <'a> &'a T: Mul<&'a T, Output = T> {
@@ -185,6 +201,7 @@ pub mod fract {
     }
 
     impl<T: IntegralDomain + Meta> Fraction<T> {
+        /// Panics if denom is zero, use try_new for values that come from the outside
         pub fn new(num: T, denom: T) -> Fraction<T> {
             if denom.is_zero() {
                 panic!("Creating a Fraction with zero denom");
@@ -193,6 +210,14 @@ pub mod fract {
             Fraction {num, denom}
         }
 
+        pub fn try_new(num: T, denom: T) -> Result<Fraction<T>, MathError> {
+            if denom.is_zero() {
+                return Err(MathError::DivisionByZero);
+            }
+
+            Ok(Fraction {num, denom})
+        }
+
         pub fn denom(&self) -> &T {
             &self.denom
         }
@@ -202,35 +227,296 @@ pub mod fract {
         }
     }
 
+    impl<T: IntegralDomain + Meta + Clone> Fraction<T> {
+        /// `1/self`, fails for a zero fraction
+        pub fn inverse(&self) -> Result<Fraction<T>, MathError> {
+            if self.num.is_zero() {
+                return Err(MathError::NonInvertible);
+            }
+
+            Ok(Fraction {num: self.denom.clone(), denom: self.num.clone()})
+        }
+    }
+
+    //arithmetic that returns an error instead of panicking or overflowing
+    impl<T: IntegralDomain + Meta + Checked> Fraction<T> {
+        pub fn checked_add(&self, rhs: &Fraction<T>) -> Result<Fraction<T>, MathError> {
+            let num = self.num.checked_mul(&rhs.denom).zip(self.denom.checked_mul(&rhs.num))
+                .and_then(|(l, r)| l.checked_add(&r));
+
+            Ok(Fraction {num: num.ok_or(MathError::Overflow)?, denom: overflow(self.denom.checked_mul(&rhs.denom))?})
+        }
+
+        pub fn checked_sub(&self, rhs: &Fraction<T>) -> Result<Fraction<T>, MathError> {
+            let num = self.num.checked_mul(&rhs.denom).zip(self.denom.checked_mul(&rhs.num))
+                .and_then(|(l, r)| l.checked_sub(&r));
+
+            Ok(Fraction {num: num.ok_or(MathError::Overflow)?, denom: overflow(self.denom.checked_mul(&rhs.denom))?})
+        }
+
+        pub fn checked_mul(&self, rhs: &Fraction<T>) -> Result<Fraction<T>, MathError> {
+            Ok(Fraction {num: overflow(self.num.checked_mul(&rhs.num))?, denom: overflow(self.denom.checked_mul(&rhs.denom))?})
+        }
+
+        pub fn checked_div(&self, rhs: &Fraction<T>) -> Result<Fraction<T>, MathError> {
+            if rhs.num.is_zero() {
+                return Err(MathError::DivisionByZero);
+            }
+
+            Ok(Fraction {num: overflow(self.num.checked_mul(&rhs.denom))?, denom: overflow(self.denom.checked_mul(&rhs.num))?})
+        }
+    }
+
+    fn overflow<T>(val: Option<T>) -> Result<T, MathError> {
+        val.ok_or(MathError::Overflow)
+    }
+
+    impl<T: IntegralDomain + Meta + PartialOrd> Fraction<T> {
+        /// Makes the denominator positive
+        pub fn normalize_sign(&mut self) {
+            if self.denom < T::zero() {
+                let num = std::mem::replace(&mut self.num, T::zero());
+                let denom = std::mem::replace(&mut self.denom, T::zero());
+
+                self.num = T::zero() - num;
+                self.denom = T::zero() - denom;
+            }
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone> Fraction<T> {
+        /// Divides num and denom by their greatest common divisor
+        pub fn reduce(&mut self) {
+            let gcd = self.num.gcd(&self.denom);
+
+            if gcd.is_zero() {
+                return;
+            }
+
+            self.num /= gcd.clone();
+            self.denom /= gcd;
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone> Fraction<T> {
+        /// Creates a Fraction in the canonical form: reduced and with a positive denominator
+        pub fn canonical(num: T, denom: T) -> Fraction<T> {
+            let mut res = fract!(num, denom);
+            res.canonicalize();
+
+            res
+        }
+
+        pub fn canonicalize(&mut self) {
+            self.reduce();
+            self.normalize_sign();
+        }
+
+        pub fn canonicalized(mut self) -> Fraction<T> {
+            self.canonicalize();
+
+            self
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + Eq> Eq for Fraction<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> {}
+
+    //equal fractions have equal canonical forms, so hashing the canonical form is consistent with PartialEq
+    impl<T> Hash for Fraction<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone + Hash {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            let canonical = self.clone().canonicalized();
+
+            canonical.num.hash(state);
+            canonical.denom.hash(state);
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + OrderedRing> PartialOrd for Fraction<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            let ord = (&self.num * &other.denom).partial_cmp(&(&other.num * &self.denom))?;
+
+            //a/b < c/d is ad < cb only when bd is positive
+            if self.denom.is_negative() != other.denom.is_negative() {
+                Some(ord.reverse())
+            } else {
+                Some(ord)
+            }
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + OrderedRing + Ord> Ord for Fraction<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.partial_cmp(other).expect("totally ordered T gave no ordering")
+        }
+    }
+
+    impl<T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Clone> Fraction<T> {
+        /// num and denom with the sign moved to num
+        fn signed_parts(&self) -> (T, T) {
+            if self.denom.is_negative() {
+                (T::zero() - self.num.clone(), T::zero() - self.denom.clone())
+            } else {
+                (self.num.clone(), self.denom.clone())
+            }
+        }
+
+        pub fn abs(&self) -> Fraction<T> {
+            Fraction {num: self.num.abs(), denom: self.denom.abs()}
+        }
+
+        pub fn signum(&self) -> T {
+            self.num.signum() * self.denom.signum()
+        }
+
+        /// The greatest integer less than or equal to the fraction
+        pub fn floor(&self) -> T {
+            let (num, denom) = self.signed_parts();
+
+            num.euclid_div(&denom)
+        }
+
+        /// The least integer greater than or equal to the fraction
+        pub fn ceil(&self) -> T {
+            let (num, denom) = self.signed_parts();
+
+            if num.euclid_rem(&denom).is_zero() {
+                num.euclid_div(&denom)
+            } else {
+                num.euclid_div(&denom) + T::one()
+            }
+        }
+
+        /// The integer part of the fraction, rounded towards zero
+        pub fn trunc(&self) -> T {
+            if self.signum().is_negative() {
+                self.ceil()
+            } else {
+                self.floor()
+            }
+        }
+
+        /// The nearest integer, half-way cases are rounded away from zero
+        pub fn round(&self) -> T {
+            let num = self.num.abs();
+            let denom = self.denom.abs();
+
+            let mut res = num.euclid_div(&denom);
+            let rem = num.euclid_rem(&denom);
+
+            if rem >= denom - rem.clone() {
+                res += T::one();
+            }
+
+            if self.signum().is_negative() {
+                T::zero() - res
+            } else {
+                res
+            }
+        }
+
+        /// The fractional part, `self - self.trunc()`, has the sign of self
+        pub fn fract(&self) -> Fraction<T> {
+            let (num, denom) = self.signed_parts();
+            let int = self.trunc();
+
+            Fraction {num: num - int * denom.clone(), denom}
+        }
+    }
+
+    impl<T: IntegralDomain + Meta + ToImaginary> ToImaginary for Fraction<T> {
+        fn to_imaginary(self) -> Imaginary {
+            self.num.to_imaginary() / self.denom.to_imaginary()
+        }
+    }
+
+    /// Prints num and denom as they are, use canonicalized to move the sign onto num first
     impl<T: IntegralDomain + Meta + Display> Display for Fraction<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "{}/{}", self.num, self.denom)
         }
     }
 
+    /// Decides when a Reducible divides its num and denom by their greatest common divisor
+    pub trait Policy<T> {
+        /// `ops` is the number of operations on the longest chain that led to the value since its last reduction
+        fn should_reduce(num: &T, denom: &T, ops: u32) -> bool;
+    }
+
+    /// Reduces after every operation
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct Always;
+
+    /// Never reduces on its own, only through `reduce()`
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct Never;
+
+    /// Reduces once every N operations
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct Every<const N: u32>;
+
+    /// Reduces when num or denom grows beyond B bits
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct BitsAbove<const B: u32>;
+
+    impl<T> Policy<T> for Always {
+        fn should_reduce(_: &T, _: &T, _: u32) -> bool {
+            true
+        }
+    }
+
+    impl<T> Policy<T> for Never {
+        fn should_reduce(_: &T, _: &T, _: u32) -> bool {
+            false
+        }
+    }
+
+    impl<T, const N: u32> Policy<T> for Every<N> {
+        fn should_reduce(_: &T, _: &T, ops: u32) -> bool {
+            ops >= N
+        }
+    }
+
+    impl<T: Bits, const B: u32> Policy<T> for BitsAbove<B> {
+        fn should_reduce(num: &T, denom: &T, _: u32) -> bool {
+            num.bits() > B || denom.bits() > B
+        }
+    }
+
+    /// Fraction that is reduced automatically according to the Policy P
     #[derive(Debug, Clone)]
-    pub struct Reducible <T: IntegralDomain + Meta + Gcd + DivAssign<T>> {
+    pub struct Reducible <T: IntegralDomain + Meta + Gcd + DivAssign<T>, P = Every<5>> {
         fract: Fraction<T>,
-        count: i32,
+        count: u32,
+        policy: PhantomData<P>,
     }
 
-    impl<T> Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
+    impl<T, P> Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn simplify(&mut self) {
-            if self.count < 4 {
-                self.count += 1;
+            self.count = self.count.saturating_add(1);
 
-                return ()
+            if P::should_reduce(&self.fract.num, &self.fract.denom, self.count) {
+                self.reduce();
             }
-            
-            let gcd = self.num().gcd(self.denom());
+        }
+
+        /// Divides num and denom by their greatest common divisor regardless of the policy
+        pub fn reduce(&mut self) {
+            self.fract.reduce();
 
-            self.fract.num /= gcd.clone();
-            self.fract.denom /= gcd;
-            
             self.count = 0;
         }
 
+        pub fn reduced(mut self) -> Reducible<T, P> {
+            self.reduce();
+
+            self
+        }
+
         pub fn num(&self) -> &T {
             &self.fract.num
         }
@@ -239,8 +525,25 @@ pub mod fract {
             &self.fract.denom
         }
 
+        /// Creates a Reducible with the policy given by the type annotation
+        pub fn with_policy(num: T, denom: T) -> Reducible<T, P> {
+            Reducible {fract: fract!(num, denom), count: 0, policy: PhantomData}
+        }
+
+        /// Fails with DivisionByZero if denom is zero, the policy is given by the type annotation like in with_policy
+        pub fn try_new(num: T, denom: T) -> Result<Reducible<T, P>, MathError> {
+            Ok(Reducible {fract: Fraction::try_new(num, denom)?, count: 0, policy: PhantomData})
+        }
+
+        pub fn inverse(&self) -> Result<Reducible<T, P>, MathError> {
+            Ok(Reducible {fract: self.fract.inverse()?, count: self.count, policy: PhantomData})
+        }
+    }
+
+    impl<T> Reducible<T>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
         pub fn new(num: T, denom: T) -> Reducible<T> {
-            Reducible {fract: fract!(num, denom), count: 0}
+            Reducible::with_policy(num, denom)
         }
     }
 
@@ -251,20 +554,100 @@ pub mod fract {
         };
     }
 
-    impl<T> PartialEq for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T>,
+    impl<T, P> PartialEq for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T>, P: Policy<T>,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn eq(&self, other: &Self) -> bool {
             self.fract == other.fract
         }
     }
 
-    impl<T> Mul for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone{
-        type Output = Reducible<T>;
+    impl<T, P> PartialOrd for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing, P: Policy<T>,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.fract.partial_cmp(&other.fract)
+        }
+    }
+
+    impl<T, P> Eq for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Eq, P: Policy<T>,
+    for <'a> &'a T: Mul<&'a T, Output = T> {}
+
+    impl<T, P> Ord for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing + Ord, P: Policy<T>,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.fract.cmp(&other.fract)
+        }
+    }
+
+    impl<T, P> Reducible<T, P>
+    where T: IntegralDomain + UnRing + Meta + Gcd + DivAssign<T> + OrderedRing + EuclidDiv + Clone, P: Policy<T> {
+        pub fn abs(&self) -> Reducible<T, P> {
+            Reducible {fract: self.fract.abs(), count: self.count, policy: PhantomData}
+        }
+
+        pub fn signum(&self) -> T {
+            self.fract.signum()
+        }
+
+        pub fn floor(&self) -> T {
+            self.fract.floor()
+        }
+
+        pub fn ceil(&self) -> T {
+            self.fract.ceil()
+        }
+
+        pub fn trunc(&self) -> T {
+            self.fract.trunc()
+        }
+
+        pub fn round(&self) -> T {
+            self.fract.round()
+        }
+
+        pub fn fract(&self) -> Reducible<T, P> {
+            let mut res = Reducible {fract: self.fract.fract(), count: self.count, policy: PhantomData};
+            res.simplify();
+
+            res
+        }
+    }
+
+    impl<T, P> Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Checked + Clone, P: Policy<T> {
+        fn checked(&self, rhs: &Self, fract: Result<Fraction<T>, MathError>) -> Result<Reducible<T, P>, MathError> {
+            let mut res = Reducible {fract: fract?, count: self.count.max(rhs.count), policy: PhantomData};
+            res.simplify();
+
+            Ok(res)
+        }
+
+        pub fn checked_add(&self, rhs: &Self) -> Result<Reducible<T, P>, MathError> {
+            self.checked(rhs, self.fract.checked_add(&rhs.fract))
+        }
+
+        pub fn checked_sub(&self, rhs: &Self) -> Result<Reducible<T, P>, MathError> {
+            self.checked(rhs, self.fract.checked_sub(&rhs.fract))
+        }
+
+        pub fn checked_mul(&self, rhs: &Self) -> Result<Reducible<T, P>, MathError> {
+            self.checked(rhs, self.fract.checked_mul(&rhs.fract))
+        }
+
+        pub fn checked_div(&self, rhs: &Self) -> Result<Reducible<T, P>, MathError> {
+            self.checked(rhs, self.fract.checked_div(&rhs.fract))
+        }
+    }
+
+    impl<T, P> Mul for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
         fn mul(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: self.fract * rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: self.fract * rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -272,12 +655,13 @@ pub mod fract {
         }
     }
 
-    impl<T> Div for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
-        type Output = Reducible<T>;
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T, P> Div for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
         fn div(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: self.fract/rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: self.fract/rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -285,12 +669,12 @@ pub mod fract {
         }
     }
 
-    impl<T> Add for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
-        type Output = Reducible<T>;
+    impl<T, P> Add for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
         fn add(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: self.fract + rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: self.fract + rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -298,12 +682,12 @@ pub mod fract {
         }
     }
 
-    impl<T> Sub for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
-        type Output = Reducible<T>;
+    impl<T, P> Sub for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
         fn sub(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: self.fract - rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: self.fract - rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -311,66 +695,72 @@ pub mod fract {
         }
     }
 
-    impl<T> Neg for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
-        type Output = Reducible<T>;
+    impl<T, P> Neg for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
         fn neg(self) -> Self::Output {
-            let mut res = Reducible {fract: -self.fract, count: self.count};
+            Reducible {fract: -self.fract, count: self.count, policy: PhantomData}
+        }
+    }
 
-            res.simplify();
+    impl<T, P> Neg for &Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
+        type Output = Reducible<T, P>;
 
-            res
+        fn neg(self) -> Self::Output {
+            Reducible {fract: -&self.fract, count: self.count, policy: PhantomData}
         }
     }
 
-    impl<T> AddAssign for Reducible<T> 
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone{
+    impl<T, P> AddAssign for Reducible<T, P> 
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn add_assign(&mut self, rhs: Self) {
             self.fract += rhs.fract;
-            self.count += rhs.count;
+            self.count = self.count.max(rhs.count);
 
             self.simplify();
         }
     }
 
-    impl<T> SubAssign for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
+    impl<T, P> SubAssign for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn sub_assign(&mut self, rhs: Self) {
             self.fract -= rhs.fract;
-            self.count += rhs.count;
+            self.count = self.count.max(rhs.count);
 
             self.simplify();
         }
     }
     
-    impl<T> MulAssign for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
+    impl<T, P> MulAssign for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn mul_assign(&mut self, rhs: Self) {
             self.fract *= rhs.fract;
-            self.count += rhs.count;
+            self.count = self.count.max(rhs.count);
 
             self.simplify();
         }
     }
 
-    impl<T> DivAssign for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T, P> DivAssign for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn div_assign(&mut self, rhs: Self) {
             self.fract /= rhs.fract;
-            self.count += rhs.count;
+            self.count = self.count.max(rhs.count);
 
             self.simplify();
         }
     }
 
-    impl<T> Add for &Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    impl<T, P> Add for &Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
     for <'a> &'a T: Mul<&'a T, Output = T>{
-        type Output = Reducible<T>;
+        type Output = Reducible<T, P>;
 
         fn add(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: &self.fract + &rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: &self.fract + &rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -378,13 +768,13 @@ pub mod fract {
         }
     }
 
-    impl<T> Sub for &Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    impl<T, P> Sub for &Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
     for <'a> &'a T: Mul<&'a T, Output = T>{
-        type Output = Reducible<T>;
+        type Output = Reducible<T, P>;
         
         fn sub(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible {fract: &self.fract - &rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible {fract: &self.fract - &rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -392,13 +782,13 @@ pub mod fract {
         }
     }
 
-    impl<T> Mul for &Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    impl<T, P> Mul for &Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
     for <'a> &'a T: Mul<&'a T, Output = T> {
-        type Output = Reducible<T>;
+        type Output = Reducible<T, P>;
 
         fn mul(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible{fract: &self.fract * &rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible{fract: &self.fract * &rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -406,13 +796,14 @@ pub mod fract {
         }
     }
 
-    impl<T> Div for &Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T, P> Div for &Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
     for <'a> &'a T: Mul<&'a T, Output = T> {
-        type Output = Reducible<T>;
+        type Output = Reducible<T, P>;
         
         fn div(self, rhs: Self) -> Self::Output {
-            let mut res = Reducible{fract: &self.fract / &rhs.fract, count: self.count + rhs.count};
+            let mut res = Reducible{fract: &self.fract / &rhs.fract, count: self.count.max(rhs.count), policy: PhantomData};
 
             res.simplify();
 
@@ -420,40 +811,488 @@ pub mod fract {
         }
     }
 
-    impl<T> Meta for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone {
+    impl<T, P> Meta for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T> {
         fn name () -> String {
             format!("Reducible<{}>", T::name())
         }
 
         fn non_zero () -> Self {
-            Reducible {fract: Fraction::non_zero(), count: 0}
+            Reducible {fract: Fraction::non_zero(), count: 0, policy: PhantomData}
+        }
+    }
+
+    impl<T, P> ToImaginary for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + ToImaginary, P: Policy<T> {
+        fn to_imaginary(self) -> Imaginary {
+            self.fract.to_imaginary()
         }
     }
 
-    impl<T> Display for Reducible<T> 
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone + Display{
+    impl<T, P> Display for Reducible<T, P> 
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone + Display, P: Policy<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             self.fract.fmt(f)
         }
     }
 
 
-    implTrait!(for Reducible : ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, Field);
+    implTrait!(for Reducible : ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, DivisionRing, Field);
+
+    impl<T, P> Ring for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn zero() -> Self {
+            Reducible {fract: Fraction::<T>::zero(), count: 0, policy: PhantomData}
+        }
+    }
+
+    impl<T, P> UnRing for Reducible<T, P>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone, P: Policy<T>,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn one() -> Self {
+            Reducible {fract: Fraction::<T>::one(), count: 0, policy: PhantomData}
+        }
+    }
+
+    /// Fraction that is always kept in the canonical form: reduced and with a positive denominator.
+    /// Equal values are structurally equal, so Rational can be used as a HashMap key
+    #[derive(Debug, Clone)]
+    pub struct Rational <T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T>> {
+        fract: Fraction<T>,
+    }
+
+    impl<T> Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        pub fn new(num: T, denom: T) -> Rational<T> {
+            Rational {fract: Fraction::canonical(num, denom)}
+        }
+
+        pub fn num(&self) -> &T {
+            &self.fract.num
+        }
+
+        pub fn denom(&self) -> &T {
+            &self.fract.denom
+        }
+
+        pub fn fraction(&self) -> &Fraction<T> {
+            &self.fract
+        }
+
+        pub fn try_new(num: T, denom: T) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(Fraction::try_new(num, denom)?))
+        }
+
+        pub fn inverse(&self) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(self.fract.inverse()?))
+        }
+    }
+
+    impl<T> Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Checked + Clone {
+        pub fn checked_add(&self, rhs: &Self) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(self.fract.checked_add(&rhs.fract)?))
+        }
+
+        pub fn checked_sub(&self, rhs: &Self) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(self.fract.checked_sub(&rhs.fract)?))
+        }
+
+        pub fn checked_mul(&self, rhs: &Self) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(self.fract.checked_mul(&rhs.fract)?))
+        }
+
+        pub fn checked_div(&self, rhs: &Self) -> Result<Rational<T>, MathError> {
+            Ok(Rational::from(self.fract.checked_div(&rhs.fract)?))
+        }
+    }
+
+    #[macro_export]
+    macro_rules! rational {
+        ($num:expr, $denom:expr) => {
+            Rational::new($num, $denom)
+        };
+    }
+
+    impl<T> From<Fraction<T>> for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn from(fract: Fraction<T>) -> Self {
+            Rational {fract: fract.canonicalized()}
+        }
+    }
+
+    impl<T> From<Rational<T>> for Fraction<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> {
+        fn from(val: Rational<T>) -> Self {
+            val.fract
+        }
+    }
+
+    impl<T> PartialEq for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.fract.num == other.fract.num && self.fract.denom == other.fract.denom
+        }
+    }
+
+    impl<T> Eq for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Eq {}
+
+    impl<T> Hash for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Hash {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.fract.num.hash(state);
+            self.fract.denom.hash(state);
+        }
+    }
 
-    impl<T> Ring for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    impl<T> PartialOrd for Rational<T>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.fract.partial_cmp(&other.fract)
+        }
+    }
+
+    impl<T> Ord for Rational<T>
+    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing + Ord,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.fract.cmp(&other.fract)
+        }
+    }
+
+    impl<T> Rational<T>
+    where T: IntegralDomain + UnRing + Meta + Gcd + DivAssign<T> + OrderedRing + EuclidDiv + Clone {
+        pub fn abs(&self) -> Rational<T> {
+            Rational {fract: self.fract.abs()}
+        }
+
+        pub fn signum(&self) -> T {
+            self.fract.num.signum()
+        }
+
+        pub fn floor(&self) -> T {
+            self.fract.floor()
+        }
+
+        pub fn ceil(&self) -> T {
+            self.fract.ceil()
+        }
+
+        pub fn trunc(&self) -> T {
+            self.fract.trunc()
+        }
+
+        pub fn round(&self) -> T {
+            self.fract.round()
+        }
+
+        pub fn fract(&self) -> Rational<T> {
+            Rational::from(self.fract.fract())
+        }
+    }
+
+    impl<T> Mul for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            Rational::from(self.fract * rhs.fract)
+        }
+    }
+
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T> Div for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            Rational::from(self.fract / rhs.fract)
+        }
+    }
+
+    impl<T> Add for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Rational::from(self.fract + rhs.fract)
+        }
+    }
+
+    impl<T> Sub for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Rational::from(self.fract - rhs.fract)
+        }
+    }
+
+    impl<T> Neg for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn neg(self) -> Self::Output {
+            Rational {fract: -self.fract}
+        }
+    }
+
+    impl<T> Neg for &Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        type Output = Rational<T>;
+
+        fn neg(self) -> Self::Output {
+            -self.clone()
+        }
+    }
+
+    impl<T> AddAssign for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn add_assign(&mut self, rhs: Self) {
+            self.fract += rhs.fract;
+            self.fract.canonicalize();
+        }
+    }
+
+    impl<T> SubAssign for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn sub_assign(&mut self, rhs: Self) {
+            self.fract -= rhs.fract;
+            self.fract.canonicalize();
+        }
+    }
+
+    impl<T> MulAssign for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn mul_assign(&mut self, rhs: Self) {
+            self.fract *= rhs.fract;
+            self.fract.canonicalize();
+        }
+    }
+
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T> DivAssign for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn div_assign(&mut self, rhs: Self) {
+            self.fract /= rhs.fract;
+            self.fract.canonicalize();
+        }
+    }
+
+    impl<T> Add for &Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        type Output = Rational<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Rational::from(&self.fract + &rhs.fract)
+        }
+    }
+
+    impl<T> Sub for &Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        type Output = Rational<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Rational::from(&self.fract - &rhs.fract)
+        }
+    }
+
+    impl<T> Mul for &Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        type Output = Rational<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            Rational::from(&self.fract * &rhs.fract)
+        }
+    }
+
+    /// Panics if rhs is zero, use checked_div for values that come from the outside
+    impl<T> Div for &Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
+    for <'a> &'a T: Mul<&'a T, Output = T> {
+        type Output = Rational<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            self.clone() / rhs.clone()
+        }
+    }
+
+    impl<T> Meta for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone {
+        fn name () -> String {
+            format!("Rational<{}>", T::name())
+        }
+
+        fn non_zero () -> Self {
+            Rational {fract: Fraction::non_zero()}
+        }
+    }
+
+    impl<T> ToImaginary for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + ToImaginary {
+        fn to_imaginary(self) -> Imaginary {
+            self.fract.to_imaginary()
+        }
+    }
+
+    impl<T> Display for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Display {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.fract.fmt(f)
+        }
+    }
+
+    implTrait!(for Rational : ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, DivisionRing, Field);
+
+    impl<T> Ring for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn zero() -> Self {
-            Reducible {fract: Fraction::<T>::zero(), count: 0}
+            Rational {fract: Fraction::<T>::zero()}
         }
     }
 
-    impl <T> UnRing for Reducible<T>
-    where T: IntegralDomain + Meta + Gcd + DivAssign<T> + Clone,
+    impl<T> UnRing for Rational<T>
+    where T: IntegralDomain + Meta + PartialOrd + Gcd + DivAssign<T> + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn one() -> Self {
-            Reducible {fract: Fraction::<T>::one(), count: 0}
+            Rational {fract: Fraction::<T>::one()}
         }
     }
 }
+
+pub mod float;
+pub mod continued;
+pub mod parse;
+pub mod decimal;
+pub mod rational_function;
+pub mod partial_fractions;
+pub mod egyptian;
+pub mod farey;
+
+#[cfg(test)]
+mod tests {
+    use crate::fract::{Fraction, Reducible, Rational, Policy, Always, Never, Every, BitsAbove};
+    use polynomial::{polynom, any_pnm::Polynomial};
+    use tech::MathError;
+
+    #[test]
+    fn display_prints_the_sign_where_it_is() {
+        assert_eq!(Fraction::new(1i64, -2).to_string(), "1/-2");
+        assert_eq!(Fraction::new(-1i64, -2).canonicalized().to_string(), "1/2");
+        assert_eq!(Fraction::new(2i64, 4).to_string(), "2/4");
+        assert_eq!(Rational::new(3i64, -6).to_string(), "-1/2");
+
+        //Display does not need an order on the ratios
+        let p: Polynomial<Rational<i64>> = polynom!(Rational::new(1, 1), Rational::new(2, 1));
+        assert_eq!(Fraction::new(p.clone(), p.clone()).to_string(), format!("{}/{}", p, p));
+    }
+
+    #[test]
+    fn try_new_with_any_policy() {
+        let res: Reducible<i64, Always> = Reducible::try_new(2, 4).unwrap() * Reducible::try_new(1, 1).unwrap();
+        assert_eq!((*res.num(), *res.denom()), (1, 2));
+
+        assert_eq!(Reducible::<i64, Always>::try_new(1, 0), Err(MathError::DivisionByZero));
+        assert_eq!(Reducible::<i64>::try_new(1, 0), Err(MathError::DivisionByZero));
+        assert_eq!(Reducible::new(1i64, 2).checked_div(&Reducible::new(0, 1)), Err(MathError::DivisionByZero));
+    }
+
+    fn parts<P: Policy<i64>>(val: &Reducible<i64, P>) -> (i64, i64) {
+        (*val.num(), *val.denom())
+    }
+
+    #[test]
+    fn always_reduces_after_each_operation() {
+        let half: Reducible<i64, Always> = Reducible::with_policy(1, 2);
+
+        assert_eq!(parts(&(half.clone() * Reducible::with_policy(2, 1))), (1, 1));
+        assert_eq!(parts(&(half.clone() + half)), (1, 1));
+    }
+
+    #[test]
+    fn never_reduces_on_its_own() {
+        let half: Reducible<i64, Never> = Reducible::with_policy(1, 2);
+        let mut res = half.clone();
+
+        for _ in 0..10 {
+            res *= Reducible::with_policy(2, 2);
+        }
+
+        assert_eq!(parts(&res), (1024, 2048));
+        assert_eq!(parts(&res.reduced()), (1, 2));
+    }
+
+    #[test]
+    fn every_n_reduces_on_the_nth_operation() {
+        let two: Reducible<i64, Every<3>> = Reducible::with_policy(2, 2);
+        let mut res = Reducible::with_policy(1, 2);
+
+        res *= two.clone();
+        res *= two.clone();
+        assert_eq!(parts(&res), (4, 8));
+
+        res *= two.clone();
+        assert_eq!(parts(&res), (1, 2));
+
+        //the count restarts after a reduction
+        res *= two;
+        assert_eq!(parts(&res), (2, 4));
+    }
+
+    #[test]
+    fn bits_above_reduces_large_parts() {
+        let half: Reducible<i64, BitsAbove<4>> = Reducible::with_policy(1, 2);
+
+        assert_eq!(parts(&(half.clone() * Reducible::with_policy(2, 2))), (2, 4));
+        assert_eq!(parts(&(half * Reducible::with_policy(16, 16))), (1, 2));
+    }
+
+    #[test]
+    fn ordering_with_negative_denominators() {
+        assert!(Fraction::new(1i64, -2) < Fraction::new(1, 3));
+        assert!(Fraction::new(-1i64, -2) > Fraction::new(1, 3));
+        assert!(Fraction::new(1i64, -2) < Fraction::new(-1, -3));
+        assert_eq!(Fraction::new(1i64, -2).cmp(&Fraction::new(-2, 4)), std::cmp::Ordering::Equal);
+
+        let mut vals = vec![Fraction::new(1i64, -3), Fraction::new(-3, -4), Fraction::new(0, -1), Fraction::new(5, -4)];
+        vals.sort();
+        assert_eq!(vals, vec![Fraction::new(-5, 4), Fraction::new(-1, 3), Fraction::new(0, 1), Fraction::new(3, 4)]);
+
+        assert!(Reducible::new(1i64, -2) < Reducible::new(-1, -3));
+        assert!(Rational::new(-1i64, 2) < Rational::new(1, -3));
+    }
+
+    #[test]
+    fn rounding_to_integers() {
+        //value, floor, ceil, trunc, round
+        let cases = [
+            ((7i64, 2), 3, 4, 3, 4),
+            ((-7, 2), -4, -3, -3, -4),
+            ((7, -2), -4, -3, -3, -4),
+            ((-7, -2), 3, 4, 3, 4),
+            ((5, -3), -2, -1, -1, -2),
+            ((4, 3), 1, 2, 1, 1),
+            ((6, -3), -2, -2, -2, -2),
+            ((0, -5), 0, 0, 0, 0),
+        ];
+
+        for ((num, denom), floor, ceil, trunc, round) in cases {
+            let val = Fraction::new(num, denom);
+
+            assert_eq!(val.floor(), floor, "floor of {}", val);
+            assert_eq!(val.ceil(), ceil, "ceil of {}", val);
+            assert_eq!(val.trunc(), trunc, "trunc of {}", val);
+            assert_eq!(val.round(), round, "round of {}", val);
+            assert_eq!(val.fract() + Fraction::new(trunc, 1), val);
+        }
+
+        assert_eq!(Fraction::new(7i64, -2).fract(), Fraction::new(-1, 2));
+    }
+}
@@ -0,0 +1,293 @@
+//! Parsing fractions from text: `3/4`, `-7`, `1 1/2`, `0.125`, `1.2e-3`, `0.1(6)`
+
+use crate::fract::{Fraction, Reducible, Rational, Policy};
+use tech::{IntegralDomain, Meta, Gcd, OrderedRing, MathError};
+use std::{
+    str::FromStr,
+    fmt::Display,
+    ops::DivAssign,
+};
+
+/// Exponents above this are rejected instead of building huge powers of ten
+const MAX_EXPONENT: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFractionErrorKind {
+    Empty,
+    /// A part of the input that is not a valid number of the target type
+    InvalidNumber(String),
+    ZeroDenominator,
+    /// Repeating part that is empty, not closed or not at the end of the decimal
+    InvalidPeriod,
+    InvalidExponent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFractionError {
+    kind: ParseFractionErrorKind,
+}
+
+impl ParseFractionError {
+    pub fn kind(&self) -> &ParseFractionErrorKind {
+        &self.kind
+    }
+}
+
+impl From<ParseFractionErrorKind> for ParseFractionError {
+    fn from(kind: ParseFractionErrorKind) -> Self {
+        ParseFractionError {kind}
+    }
+}
+
+impl Display for ParseFractionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseFractionErrorKind::Empty => write!(f, "cannot parse a fraction from an empty string"),
+            ParseFractionErrorKind::InvalidNumber(s) => write!(f, "`{}` is not a valid number", s),
+            ParseFractionErrorKind::ZeroDenominator => write!(f, "the denominator of a fraction can not be zero"),
+            ParseFractionErrorKind::InvalidPeriod => write!(f, "the repeating part has to be non-empty digits in parentheses at the end of the decimal"),
+            ParseFractionErrorKind::InvalidExponent => write!(f, "invalid or too large exponent"),
+        }
+    }
+}
+
+impl std::error::Error for ParseFractionError {}
+
+impl From<ParseFractionError> for MathError {
+    fn from(err: ParseFractionError) -> Self {
+        match err.kind {
+            ZeroDenominator => MathError::DivisionByZero,
+            _ => MathError::Parse(err.to_string()),
+        }
+    }
+}
+
+use ParseFractionErrorKind::*;
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Splits the sign off, returns true for a minus
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+fn digits<T: FromStr>(s: &str) -> Result<T, ParseFractionError> {
+    if !is_digits(s) {
+        return Err(InvalidNumber(s.to_string()).into());
+    }
+
+    s.parse().map_err(|_| InvalidNumber(s.to_string()).into())
+}
+
+fn negate<T: IntegralDomain>(val: T, negative: bool) -> T {
+    if negative {
+        T::zero() - val
+    } else {
+        val
+    }
+}
+
+fn integer<T: IntegralDomain + FromStr>(s: &str) -> Result<T, ParseFractionError> {
+    let (negative, rest) = split_sign(s.trim());
+
+    Ok(negate(digits(rest)?, negative))
+}
+
+fn fraction<T: IntegralDomain + Meta>(num: T, denom: T) -> Result<Fraction<T>, ParseFractionError> {
+    if denom.is_zero() {
+        return Err(ZeroDenominator.into());
+    }
+
+    Ok(Fraction::new(num, denom))
+}
+
+/// `[sign]int[.frac][(period)][e[sign]exp]`
+fn decimal<T: IntegralDomain + Meta + FromStr>(s: &str) -> Result<Fraction<T>, ParseFractionError> {
+    let (negative, s) = split_sign(s);
+
+    let (mantissa, exp) = match s.find(['e', 'E']) {
+        Some(i) => {
+            let (exp_negative, exp) = split_sign(&s[i + 1..]);
+
+            if !is_digits(exp) {
+                return Err(InvalidExponent.into());
+            }
+
+            let exp: usize = exp.parse().map_err(|_| InvalidExponent)?;
+
+            if exp > MAX_EXPONENT {
+                return Err(InvalidExponent.into());
+            }
+
+            (&s[..i], if exp_negative {-(exp as i64)} else {exp as i64})
+        }
+        None => (s, 0),
+    };
+
+    let (int, rest) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let (frac, period) = match rest.find('(') {
+        Some(i) => {
+            let period = rest[i + 1..].strip_suffix(')').ok_or(InvalidPeriod)?;
+
+            if !is_digits(period) {
+                return Err(InvalidPeriod.into());
+            }
+
+            (&rest[..i], period)
+        }
+        None => (rest, ""),
+    };
+
+    if (int.is_empty() && frac.is_empty() && period.is_empty())
+        || !(int.is_empty() || is_digits(int))
+        || !(frac.is_empty() || is_digits(frac)) {
+        return Err(InvalidNumber(mantissa.to_string()).into());
+    }
+
+    let shift = |zeros: i64| "0".repeat(zeros.max(0) as usize);
+
+    //int.frac(period) * 10^exp = (int frac period - int frac)/(10^len(frac) * (10^len(period) - 1)) * 10^exp
+    let base = format!("{}{}", int, frac);
+    let base = if base.is_empty() {"0".to_string()} else {base};
+
+    let denom_zeros = frac.len() as i64 - exp;
+
+    let (num, denom): (T, T) = if period.is_empty() {
+        (digits(&format!("{}{}", base, shift(-denom_zeros)))?, digits(&format!("1{}", shift(denom_zeros)))?)
+    } else {
+        let full: T = digits(&format!("{}{}{}", base, period, shift(-denom_zeros)))?;
+        let part: T = digits(&format!("{}{}", base, shift(-denom_zeros)))?;
+
+        (full - part, digits(&format!("{}{}", "9".repeat(period.len()), shift(denom_zeros)))?)
+    };
+
+    fraction(negate(num, negative), denom)
+}
+
+impl<T: IntegralDomain + Meta + FromStr + Clone> FromStr for Fraction<T> {
+    type Err = ParseFractionError;
+
+    /// Accepts integers, `num/denom`, mixed numbers `whole num/denom`, decimals with an exponent
+    /// and repeating decimals like `0.1(6)`. The result is not reduced
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(Empty.into());
+        }
+
+        let Some((left, denom)) = s.split_once('/') else {
+            return decimal(s);
+        };
+
+        let denom: T = integer(denom)?;
+        let mut parts = left.split_whitespace();
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(num), None, None) => fraction(integer(num)?, denom),
+            (Some(whole), Some(num), None) => {
+                let (negative, whole) = split_sign(whole);
+
+                let whole: T = digits(whole)?;
+                let num: T = digits(num)?;
+
+                fraction(negate(whole * denom.clone() + num, negative), denom)
+            }
+            _ => Err(InvalidNumber(left.trim().to_string()).into()),
+        }
+    }
+}
+
+impl<T, P> FromStr for Reducible<T, P>
+where T: IntegralDomain + Meta + Gcd + DivAssign<T> + FromStr + Clone, P: Policy<T> {
+    type Err = ParseFractionError;
+
+    /// Same formats as for Fraction, the result is reduced
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fract: Fraction<T> = s.parse()?;
+
+        Ok(Reducible::with_policy(fract.num().clone(), fract.denom().clone()).reduced())
+    }
+}
+
+impl<T> FromStr for Rational<T>
+where T: IntegralDomain + Meta + OrderedRing + Gcd + DivAssign<T> + FromStr + Clone {
+    type Err = ParseFractionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fract: Fraction<T> = s.parse()?;
+
+        Ok(Rational::from(fract))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fract::Never;
+
+    fn parts(s: &str) -> Result<(i64, i64), ParseFractionErrorKind> {
+        s.parse::<Fraction<i64>>().map(|v| (*v.num(), *v.denom())).map_err(|err| err.kind().clone())
+    }
+
+    #[test]
+    fn signs_and_mixed_numbers() {
+        assert_eq!(parts("2/-4"), Ok((2, -4)));
+        assert_eq!(parts("-2/4"), Ok((-2, 4)));
+        assert_eq!(parts("+3/4"), Ok((3, 4)));
+        assert_eq!(parts("-7"), Ok((-7, 1)));
+        assert_eq!(parts("-1 1/2"), Ok((-3, 2)));
+        assert_eq!(parts("2 3/4"), Ok((11, 4)));
+        assert_eq!(parts("1 -1/2"), Err(InvalidNumber("-1".to_string())));
+        assert_eq!(parts("1 2 3/4"), Err(InvalidNumber("1 2 3".to_string())));
+    }
+
+    #[test]
+    fn decimals_and_exponents() {
+        assert_eq!(parts("0.125"), Ok((125, 1000)));
+        assert_eq!(parts("-.5"), Ok((-5, 10)));
+        assert_eq!(parts("0.1(6)"), Ok((15, 90)));
+        assert_eq!(parts(".(3)"), Ok((3, 9)));
+        assert_eq!(parts("1.2e-3"), Ok((12, 10000)));
+        assert_eq!(parts("2.5E2"), Ok((250, 1)));
+        assert_eq!(parts("0e10000"), Ok((0, 1)));
+        assert_eq!(parts("0e10001"), Err(InvalidExponent));
+        assert_eq!(parts("1e"), Err(InvalidExponent));
+        assert_eq!(parts("1.2(3"), Err(InvalidPeriod));
+        assert_eq!(parts("0.()"), Err(InvalidPeriod));
+        assert_eq!(parts("."), Err(InvalidNumber(".".to_string())));
+        assert_eq!(parts("99999999999999999999"), Err(InvalidNumber("99999999999999999999".to_string())));
+    }
+
+    #[test]
+    fn zero_denominator_and_whitespace() {
+        assert_eq!(parts("1/0"), Err(ZeroDenominator));
+        assert_eq!(MathError::from("1/0".parse::<Fraction<i64>>().unwrap_err()), MathError::DivisionByZero);
+        assert_eq!(parts(""), Err(Empty));
+        assert_eq!(parts("   "), Err(Empty));
+        assert_eq!(parts("  3 / 4 "), Ok((3, 4)));
+        assert_eq!(parts(" -1  1/2\t"), Ok((-3, 2)));
+    }
+
+    #[test]
+    fn from_str_for_each_type() {
+        let reducible: Reducible<i64> = "6/8".parse().unwrap();
+        assert_eq!((*reducible.num(), *reducible.denom()), (3, 4));
+
+        let never: Reducible<i64, Never> = "0.1(6)".parse().unwrap();
+        assert_eq!((*never.num(), *never.denom()), (1, 6));
+
+        let rational: Rational<i64> = "6/-8".parse().unwrap();
+        assert_eq!((*rational.num(), *rational.denom()), (-3, 4));
+
+        assert!("1/0".parse::<Rational<i64>>().is_err());
+        assert!("x".parse::<Reducible<i64>>().is_err());
+    }
+}
@@ -0,0 +1,336 @@
+//! Partial fraction decomposition `num/denom = q + Σ a(x)/f(x)^k` with `deg a < deg f`
+
+use crate::{
+    fract::{Reducible, Rational, Policy},
+    rational_function::RationalFunction,
+};
+use polynomial::{any_pnm::Polynomial, polynom};
+use tech::{Field, IntegralDomain, Ring, UnRing, Meta, OrderedRing, EuclidDiv, Gcd, MathError};
+use std::{
+    ops::{Mul, Div, Sub, Neg, DivAssign},
+    fmt::Display,
+};
+
+/// The term `numerator/factor^power`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFraction<T: Field> {
+    pub numerator: Polynomial<T>,
+    pub factor: Polynomial<T>,
+    pub power: usize,
+}
+
+/// Polynomial part plus proper fractions over powers of pairwise coprime factors.
+/// The terms of every factor go in increasing order of power
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFractions<T: Field> {
+    pub polynomial: Polynomial<T>,
+    pub terms: Vec<PartialFraction<T>>,
+}
+
+fn pow<T>(val: &Polynomial<T>, k: usize) -> Polynomial<T>
+where T: Field + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> {
+    let mut res = polynom!(T::one());
+
+    for _ in 0..k {
+        res = &res * val;
+    }
+
+    res
+}
+
+/// Ratios that find the roots of a polynomial exactly, `PartialFractions::new` splits the
+/// matching linear factors off the denominator. The default finds none
+pub trait RationalRoots: Field + Sized {
+    /// The roots of p in increasing order
+    fn rational_roots(_: &Polynomial<Self>) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+impl RationalRoots for f32 {}
+impl RationalRoots for f64 {}
+
+impl<T> RationalRoots for Rational<T>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> {
+    fn rational_roots(p: &Polynomial<Self>) -> Vec<Self> {
+        rational_roots(p, |v| (v.num().clone(), v.denom().clone()), Rational::new)
+    }
+}
+
+impl<T, P> RationalRoots for Reducible<T, P>
+where T: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + DivAssign<T> + Clone, P: Policy<T> + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> {
+    fn rational_roots(p: &Polynomial<Self>) -> Vec<Self> {
+        rational_roots(p, |v| (v.num().clone(), v.denom().clone()), Reducible::with_policy)
+    }
+}
+
+/// Positive divisors of the positive integer n
+fn divisors<I: UnRing + OrderedRing + EuclidDiv + Clone>(n: &I) -> Vec<I> {
+    let mut res = Vec::new();
+    let mut i = I::one();
+
+    //i <= n/i instead of i*i <= n, which overflows
+    while i <= n.euclid_div(&i) {
+        if n.euclid_rem(&i).is_zero() {
+            let pair = n.euclid_div(&i);
+
+            if pair != i {
+                res.push(pair);
+            }
+
+            res.push(i.clone());
+        }
+
+        i += I::one();
+    }
+
+    res
+}
+
+/// Rational root test: once the denominators are cleared, every root `r/s` in lowest terms
+/// has r dividing the lowest non-zero ratio and s dividing the leading one
+fn rational_roots<I, T>(p: &Polynomial<T>, parts: impl Fn(&T) -> (I, I), ratio: impl Fn(I, I) -> T) -> Vec<T>
+where I: IntegralDomain + UnRing + Meta + OrderedRing + EuclidDiv + Gcd + Clone, T: Field + PartialOrd + Clone {
+    let parts: Vec<(I, I)> = p.get().iter().map(parts).collect();
+
+    let lcm = parts.iter().fold(I::one(), |acc, (_, denom)| {
+        let denom = denom.abs();
+        let gcd = acc.gcd(&denom);
+
+        acc.euclid_div(&gcd) * denom
+    });
+
+    let ints: Vec<I> = parts.into_iter().map(|(num, denom)| num * lcm.euclid_div(&denom)).collect();
+
+    let Some(low) = ints.iter().position(|v| !v.is_zero()) else {
+        return Vec::new();
+    };
+
+    let mut res = if low > 0 {vec![T::zero()]} else {Vec::new()};
+
+    if low == ints.len() - 1 {
+        return res;
+    }
+
+    let lead = ints[ints.len() - 1].abs();
+
+    for r in divisors(&ints[low].abs()) {
+        for s in divisors(&lead) {
+            if !r.gcd(&s).is_one() {
+                continue;
+            }
+
+            for num in [r.clone(), I::zero() - r.clone()] {
+                let root = ratio(num, s.clone());
+
+                if p.eval(&root).is_zero() {
+                    res.push(root);
+                }
+            }
+        }
+    }
+
+    res.sort_by(|a, b| a.partial_cmp(b).expect("rational roots are ordered"));
+
+    res
+}
+
+impl<T> PartialFractions<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    /// Decomposition over the rational linear factors and the remaining square-free factors of denom.
+    /// The factorization needs exact gcds, for float ratios pass known factors to `with_factors`.
+    ///
+    /// Name the ratios with a turbofish, `PartialFractions::<Rational<i64>>::new(&num, &denom)`,
+    /// without one the trait solver overflows on the bounds of the ratios (E0275)
+    pub fn new(num: &Polynomial<T>, denom: &Polynomial<T>) -> Result<PartialFractions<T>, MathError>
+    where T: RationalRoots {
+        if denom.is_zero() {
+            return Err(MathError::DivisionByZero);
+        }
+
+        let num = num * &(T::one() / denom.lead().clone());
+        let mut factors = Vec::new();
+
+        for (factor, k) in denom.square_free_factorization() {
+            let mut rest = factor;
+
+            for root in T::rational_roots(&rest) {
+                let linear = polynom!(T::one(), T::zero() - root);
+
+                rest = rest.div_with_rem(&linear).0;
+                factors.push((linear, k));
+            }
+
+            if rest.deg() > 0 {
+                factors.push((rest, k));
+            }
+        }
+
+        Self::with_factors(&num, &factors)
+    }
+
+    /// Decomposition of `num / Π factor^multiplicity`.
+    /// Fails with NonInvertible if the factors are not pairwise coprime
+    pub fn with_factors(num: &Polynomial<T>, factors: &[(Polynomial<T>, usize)]) -> Result<PartialFractions<T>, MathError> {
+        let powers: Vec<Polynomial<T>> = factors.iter().map(|(f, k)| pow::<T>(f, *k)).collect();
+        let mut rest = powers.iter().fold(polynom!(T::one()), |acc, p| &acc * p);
+
+        let (polynomial, mut r) = num.try_div_with_rem(&rest)?;
+        let mut terms = Vec::new();
+
+        for ((factor, k), p) in factors.iter().zip(powers) {
+            rest = rest.div_with_rem(&p).0;
+
+            if p.deg() == 0 {
+                r *= T::one() / p.lead().clone();
+
+                continue;
+            }
+
+            //s p + t rest = 1, so r/(p rest) = r t/p + r s/rest
+            let (g, _, t) = p.ext_gcd(&rest);
+
+            if g.deg() > 0 {
+                return Err(MathError::NonInvertible);
+            }
+
+            let mut a = &(&r * &t) % &p;
+            r = (r - &a * &rest).div_with_rem(&p).0;
+
+            //a = Σ c_m factor^m, and c_m/factor^(k - m) are the terms
+            let mut digits = Vec::with_capacity(*k);
+
+            for _ in 0..*k {
+                let (q, c) = a.div_with_rem(factor);
+
+                digits.push(c);
+                a = q;
+            }
+
+            for (m, numerator) in digits.into_iter().enumerate().rev() {
+                if !numerator.is_zero() {
+                    terms.push(PartialFraction {numerator, factor: factor.clone(), power: k - m});
+                }
+            }
+        }
+
+        Ok(PartialFractions {polynomial, terms})
+    }
+
+    /// Sums the decomposition back up
+    pub fn to_rational_function(&self) -> RationalFunction<T> {
+        self.terms.iter().fold(RationalFunction::<T>::from(self.polynomial.clone()), |acc, term| {
+            acc + RationalFunction::<T>::new(term.numerator.clone(), pow::<T>(&term.factor, term.power))
+        })
+    }
+}
+
+impl<T> RationalFunction<T>
+where T: RationalRoots + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    pub fn partial_fractions(&self) -> PartialFractions<T> {
+        PartialFractions::<T>::new(self.num(), self.denom()).expect("the denominator of a rational function is not zero")
+    }
+}
+
+impl<T: Field> Display for PartialFraction<T>
+where for <'a> &'a T: Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})/({})", self.numerator.to_string().trim(), self.factor.to_string().trim())?;
+
+        if self.power > 1 {
+            write!(f, "^{}", self.power)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Field + Clone> Display for PartialFractions<T>
+where for <'a> &'a T: Mul<&'a T, Output = T> + Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut items: Vec<String> = self.terms.iter().map(|t| t.to_string()).collect();
+
+        if !self.polynomial.is_zero() || items.is_empty() {
+            items.insert(0, self.polynomial.to_string().trim().to_string());
+        }
+
+        write!(f, "{}", items.join(" + "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i64, denom: i64) -> Rational<i64> {
+        Rational::new(num, denom)
+    }
+
+    fn term(numerator: Polynomial<Rational<i64>>, factor: Polynomial<Rational<i64>>, power: usize) -> PartialFraction<Rational<i64>> {
+        PartialFraction {numerator, factor, power}
+    }
+
+    #[test]
+    fn splits_the_rational_linear_factors() {
+        //x^4/(2x^2 - 2) = x^2/2 + 1/2 - (1/4)/(x + 1) + (1/4)/(x - 1)
+        let num = polynom!(r(1, 1), r(0, 1), r(0, 1), r(0, 1), r(0, 1));
+        let denom = polynom!(r(2, 1), r(0, 1), r(-2, 1));
+        let res = PartialFractions::<Rational<i64>>::new(&num, &denom).unwrap();
+
+        assert_eq!(res.polynomial, polynom!(r(1, 2), r(0, 1), r(1, 2)));
+        assert_eq!(res.terms, vec![
+            term(polynom!(r(-1, 4)), polynom!(r(1, 1), r(1, 1)), 1),
+            term(polynom!(r(1, 4)), polynom!(r(1, 1), r(-1, 1)), 1),
+        ]);
+        assert_eq!(res.to_rational_function(), RationalFunction::<Rational<i64>>::new(num, denom));
+
+        //1/((s + 1)(s + 2)) = -1/(s + 2) + 1/(s + 1)
+        let one = polynom!(r(1, 1));
+        let denom = polynom!(r(1, 1), r(3, 1), r(2, 1));
+        let res = PartialFractions::<Rational<i64>>::new(&one, &denom).unwrap();
+
+        assert!(res.polynomial.is_zero());
+        assert_eq!(res.terms, vec![
+            term(polynom!(r(-1, 1)), polynom!(r(1, 1), r(2, 1)), 1),
+            term(polynom!(r(1, 1)), polynom!(r(1, 1), r(1, 1)), 1),
+        ]);
+        assert_eq!(res.to_rational_function(), RationalFunction::<Rational<i64>>::new(one, denom));
+    }
+
+    #[test]
+    fn keeps_factors_without_rational_roots() {
+        //(x^2 + 1)/((x - 1)^2 (x^2 + 2))
+        let num = polynom!(r(1, 1), r(0, 1), r(1, 1));
+        let denom = &pow::<Rational<i64>>(&polynom!(r(1, 1), r(-1, 1)), 2) * &polynom!(r(1, 1), r(0, 1), r(2, 1));
+        let res = PartialFractions::<Rational<i64>>::new(&num, &denom).unwrap();
+
+        let factors: Vec<_> = res.terms.iter().map(|t| (t.factor.clone(), t.power)).collect();
+
+        assert_eq!(factors, vec![
+            (polynom!(r(1, 1), r(0, 1), r(2, 1)), 1),
+            (polynom!(r(1, 1), r(-1, 1)), 1),
+            (polynom!(r(1, 1), r(-1, 1)), 2),
+        ]);
+        assert_eq!(res.to_rational_function(), RationalFunction::<Rational<i64>>::new(num, denom));
+    }
+
+    #[test]
+    fn roots_with_denominators() {
+        //6x^3 - 5x^2 + x = x(2x - 1)(3x - 1)
+        let p = polynom!(r(6, 1), r(-5, 1), r(1, 1), r(0, 1));
+        assert_eq!(Rational::<i64>::rational_roots(&p), vec![r(0, 1), r(1, 3), r(1, 2)]);
+
+        //x^2/4 - 1/9 has the roots -2/3 and 2/3, x^2 + 1 has none
+        let p = polynom!(r(1, 4), r(0, 1), r(-1, 9));
+        assert_eq!(Rational::<i64>::rational_roots(&p), vec![r(-2, 3), r(2, 3)]);
+        assert!(Rational::<i64>::rational_roots(&polynom!(r(1, 1), r(0, 1), r(1, 1))).is_empty());
+
+        let p: Polynomial<Reducible<i64>> = polynom!(Reducible::new(2, 1), Reducible::new(-1, 1));
+        assert_eq!(Reducible::<i64>::rational_roots(&p), vec![Reducible::new(1, 2)]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Rational functions: fractions of polynomials that are kept reduced and with a monic denominator
+
+use crate::fract::{Reducible, Always};
+use polynomial::any_pnm::Polynomial;
+use tech::{Field, DivisionRing, Ring, UnRing, IntegralDomain, Meta, AssAdd, AssMul, ComAdd, ComMul, MathError};
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+    fmt::Display,
+};
+
+/// `num(x)/denom(x)` in the canonical form: num and denom are coprime and the denominator is monic,
+/// so equal functions have equal num and denom
+///
+/// T can not be inferred through the bounds on references, so it has to be named: `RationalFunction::<f64>::new(num, denom)`
+#[derive(Debug)]
+pub struct RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fract: Reducible<Polynomial<T>, Always>,
+}
+
+impl<T> RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    /// Panics if denom is zero
+    pub fn new(num: Polynomial<T>, denom: Polynomial<T>) -> RationalFunction<T> {
+        Self::from_reduced(Reducible::with_policy(num, denom).reduced())
+    }
+
+    pub fn try_new(num: Polynomial<T>, denom: Polynomial<T>) -> Result<RationalFunction<T>, MathError> {
+        if denom.is_zero() {
+            return Err(MathError::DivisionByZero);
+        }
+
+        Ok(Self::new(num, denom))
+    }
+
+    /// Makes the denominator of an already reduced fraction monic
+    fn from_reduced(fract: Reducible<Polynomial<T>, Always>) -> RationalFunction<T> {
+        let lead = fract.denom().lead().clone();
+
+        if lead.is_one() {
+            return Self {fract};
+        }
+
+        let inv = T::one() / lead;
+
+        Self {fract: Reducible::with_policy(fract.num().clone() * inv.clone(), fract.denom().clone() * inv)}
+    }
+
+    pub fn num(&self) -> &Polynomial<T> {
+        self.fract.num()
+    }
+
+    pub fn denom(&self) -> &Polynomial<T> {
+        self.fract.denom()
+    }
+
+    /// The value at x, fails with DivisionByZero if x is a pole
+    pub fn evaluate(&self, x: &T) -> Result<T, MathError> {
+        let denom = self.denom().eval(x);
+
+        if denom.is_zero() {
+            return Err(MathError::DivisionByZero);
+        }
+
+        Ok(self.num().eval(x) / denom)
+    }
+
+    /// `(num' denom - num denom') / denom^2`
+    pub fn derivative(&self) -> RationalFunction<T> {
+        let (num, denom) = (self.num(), self.denom());
+
+        let res_num = &num.derivative() * denom - num * &denom.derivative();
+
+        Self::new(res_num, denom * denom)
+    }
+
+    pub fn inverse(&self) -> Result<RationalFunction<T>, MathError> {
+        if self.num().is_zero() {
+            return Err(MathError::NonInvertible);
+        }
+
+        Ok(Self::new(self.denom().clone(), self.num().clone()))
+    }
+}
+
+//written by hand and with Self everywhere: leaving T to inference makes the trait solver recurse into Polynomial<Polynomial<...>>
+impl<T> Clone for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn clone(&self) -> Self {
+        Self {fract: self.fract.clone()}
+    }
+}
+
+impl<T> From<Polynomial<T>> for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn from(val: Polynomial<T>) -> Self {
+        Self {fract: Reducible::with_policy(val, Polynomial::<T>::one())}
+    }
+}
+
+impl<T> PartialEq for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num() == other.num() && self.denom() == other.denom()
+    }
+}
+
+macro_rules! implOp {
+    ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+        $(
+            impl<T> $op for RationalFunction<T>
+            where T: Field + Meta + Clone,
+            for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+                type Output = RationalFunction<T>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Self::from_reduced(self.fract.$method(rhs.fract))
+                }
+            }
+
+            impl<T> $op for &RationalFunction<T>
+            where T: Field + Meta + Clone,
+            for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+                type Output = RationalFunction<T>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    RationalFunction::<T>::from_reduced((&self.fract).$method(&rhs.fract))
+                }
+            }
+
+            impl<T> $op_assign for RationalFunction<T>
+            where T: Field + Meta + Clone,
+            for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+                fn $method_assign(&mut self, rhs: Self) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign; Div, div, DivAssign, div_assign);
+
+impl<T> Neg for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    type Output = RationalFunction<T>;
+
+    fn neg(self) -> Self::Output {
+        Self {fract: Reducible::with_policy(-self.fract.num().clone(), self.fract.denom().clone())}
+    }
+}
+
+impl<T> Ring for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn zero() -> Self {
+        Self::from(Polynomial::<T>::zero())
+    }
+}
+
+impl<T> UnRing for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn one() -> Self {
+        Self::from(Polynomial::<T>::one())
+    }
+}
+
+impl<T> Meta for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    fn non_zero() -> Self {
+        Self::one()
+    }
+
+    fn name() -> String {
+        format!("RationalFunction<{}>", T::name())
+    }
+}
+
+macro_rules! implTrait {
+    ($($i: ident),*) => {
+        $(
+            impl<T> $i for RationalFunction<T>
+            where T: Field + Meta + Clone,
+            for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {}
+        )*
+    };
+}
+
+implTrait!(ComAdd, ComMul, AssAdd, AssMul, IntegralDomain, DivisionRing, Field);
+
+impl<T> Display for RationalFunction<T>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> + Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let num = self.num().to_string();
+
+        if self.denom().is_one() {
+            return write!(f, "{}", num.trim());
+        }
+
+        write!(f, "({})/({})", num.trim(), self.denom().to_string().trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fract::Rational;
+
+    #[test]
+    fn name_of_the_ratios() {
+        assert_eq!(RationalFunction::<Rational<i64>>::name(), "RationalFunction<Rational<i64>>");
+    }
+}
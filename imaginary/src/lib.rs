@@ -1,8 +1,9 @@
 pub mod imgn {
-    use tech::{AssAdd, AssMul, ComAdd, ComMul, Ring, UnRing, IntegralDomain, Field, Meta};
+    use tech::{AssAdd, AssMul, ComAdd, ComMul, Ring, UnRing, IntegralDomain, DivisionRing, Field, Meta, ApproxEq, Tolerance, Magnitude};
     use std::{
-        fmt::Display,
-        ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg}
+        fmt::{Display, LowerExp, UpperExp, Formatter, Alignment},
+        ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg},
+        iter::{Sum, Product},
     };
     
     pub trait ToImaginary {
@@ -20,6 +21,32 @@ pub mod imgn {
             Imaginary {real: self as f64, imaginary: 0.0}
         }
     }
+
+    impl ToImaginary for &f64 {
+        fn to_imaginary(self) -> Imaginary {
+            (*self).to_imaginary()
+        }
+    }
+
+    impl ToImaginary for Imaginary {
+        fn to_imaginary(self) -> Imaginary {
+            self
+        }
+    }
+
+    macro_rules! implToImaginary {
+        ($($typ: ty),*) => {
+            $(
+                impl ToImaginary for $typ {
+                    fn to_imaginary(self) -> Imaginary {
+                        Imaginary {real: self as f64, imaginary: 0.0}
+                    }
+                }
+            )*
+        };
+    }
+
+    implToImaginary!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
     
     #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct Imaginary {
@@ -36,19 +63,27 @@ pub mod imgn {
             Imaginary {real, imaginary}
         }
 
+        pub fn real(&self) -> f64 {
+            self.real
+        }
+
+        pub fn imaginary(&self) -> f64 {
+            self.imaginary
+        }
+
         pub fn conjugated(&self) -> Imaginary {
             Imaginary {real:self.real, imaginary: -self.imaginary}
         }
 
         pub fn into_polar(self) -> (f64, f64) {
             let r = self.abs();
-            let angle = (self.imaginary/self.real).atan();
+            let angle = self.imaginary.atan2(self.real);
 
             (r, angle)
         }
 
         pub fn root(&self, n: i32) -> Vec<Imaginary> {
-            let (r, angle) = self.clone().into_polar();
+            let (r, angle) = self.into_polar();
             let r_root = r.powf(1.0/(n as f64));
 
             let mut res  = Vec::new();
@@ -65,9 +100,245 @@ pub mod imgn {
         } 
     }
 
+    /// Notation used for the real numbers inside of a formatted Imaginary
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Notation {
+        Fixed,
+        /// `1.5e3`, uppercase selects `1.5E3`
+        Scientific {upper: bool},
+        /// Like Scientific but the exponent is always a multiple of 3
+        Engineering {upper: bool},
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AngleUnit {
+        Degrees,
+        Radians,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Form {
+        /// `(re + im i)`
+        Cartesian,
+        /// `r∠θ`
+        Polar(AngleUnit),
+    }
+
+    /// Builder describing how an Imaginary is turned into a string.
+    /// Options that are not set explicitly are taken from the formatter (`{:.3}`, `{:#}`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Format {
+        precision: Option<usize>,
+        notation: Notation,
+        form: Form,
+        unit: char,
+        suppress_zero: Option<bool>,
+        parentheses: bool,
+    }
+
+    impl Default for Format {
+        fn default() -> Self {
+            Format {
+                precision: None,
+                notation: Notation::Fixed,
+                form: Form::Cartesian,
+                unit: 'i',
+                suppress_zero: None,
+                parentheses: true,
+            }
+        }
+    }
+
+    impl Format {
+        pub fn new() -> Format {
+            Format::default()
+        }
+
+        pub fn precision(mut self, precision: usize) -> Format {
+            self.precision = Some(precision);
+            self
+        }
+
+        pub fn notation(mut self, notation: Notation) -> Format {
+            self.notation = notation;
+            self
+        }
+
+        pub fn scientific(self) -> Format {
+            self.notation(Notation::Scientific {upper: false})
+        }
+
+        pub fn engineering(self) -> Format {
+            self.notation(Notation::Engineering {upper: false})
+        }
+
+        pub fn cartesian(mut self) -> Format {
+            self.form = Form::Cartesian;
+            self
+        }
+
+        pub fn polar(mut self, unit: AngleUnit) -> Format {
+            self.form = Form::Polar(unit);
+            self
+        }
+
+        /// Sets the symbol of the imaginary unit, usually `i` or `j`
+        pub fn unit(mut self, unit: char) -> Format {
+            self.unit = unit;
+            self
+        }
+
+        /// When set, zero parts are omitted: `3`, `4i` instead of `(3 + 0i)`, `(0 + 4i)`
+        pub fn suppress_zero(mut self, suppress: bool) -> Format {
+            self.suppress_zero = Some(suppress);
+            self
+        }
+
+        /// Whether an Imaginary with both parts printed is wrapped in parentheses
+        pub fn parentheses(mut self, parentheses: bool) -> Format {
+            self.parentheses = parentheses;
+            self
+        }
+
+        fn component(&self, val: f64, precision: Option<usize>) -> String {
+            match (self.notation, precision) {
+                (Notation::Fixed, Some(p)) => format!("{:.*}", p, val),
+                (Notation::Fixed, None) => format!("{}", val),
+                (Notation::Scientific {upper: false}, Some(p)) => format!("{:.*e}", p, val),
+                (Notation::Scientific {upper: false}, None) => format!("{:e}", val),
+                (Notation::Scientific {upper: true}, Some(p)) => format!("{:.*E}", p, val),
+                (Notation::Scientific {upper: true}, None) => format!("{:E}", val),
+                (Notation::Engineering {upper}, p) => engineering(val, p, upper),
+            }
+        }
+
+        fn render(&self, val: &Imaginary, precision: Option<usize>, alternate: bool) -> String {
+            match self.form {
+                Form::Polar(unit) => {
+                    let (r, angle) = val.into_polar();
+
+                    match unit {
+                        AngleUnit::Degrees => format!("{}∠{}°", self.component(r, precision), self.component(angle.to_degrees(), precision)),
+                        AngleUnit::Radians => format!("{}∠{}", self.component(r, precision), self.component(angle, precision)),
+                    }
+                }
+
+                Form::Cartesian => {
+                    let suppress_zero = self.suppress_zero.unwrap_or(alternate);
+
+                    let real = self.component(val.real, precision);
+                    let imaginary = self.component(val.imaginary.abs(), precision);
+                    let negative = val.imaginary < 0.0;
+
+                    if suppress_zero && val.imaginary == 0.0 {
+                        return real;
+                    }
+
+                    if suppress_zero && val.real == 0.0 {
+                        return format!("{}{}{}", if negative {"-"} else {""}, imaginary, self.unit);
+                    }
+
+                    let sign = if negative {"-"} else {"+"};
+
+                    if self.parentheses {
+                        format!("({} {} {}{})", real, sign, imaginary, self.unit)
+                    } else {
+                        format!("{} {} {}{}", real, sign, imaginary, self.unit)
+                    }
+                }
+            }
+        }
+
+        /// Writes the rendered value with the width, fill and alignment of the formatter, left aligned by default.
+        /// The precision of the formatter goes to the parts and does not cut the string like `f.pad` would
+        fn write(&self, val: &Imaginary, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let res = self.render(val, self.precision.or(f.precision()), f.alternate());
+            let len = res.chars().count();
+
+            let padding = match f.width() {
+                Some(width) if width > len => width - len,
+                _ => return f.write_str(&res),
+            };
+
+            let (before, after) = match f.align() {
+                Some(Alignment::Right) => (padding, 0),
+                Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                _ => (0, padding),
+            };
+
+            let fill = f.fill().to_string();
+
+            write!(f, "{}{}{}", fill.repeat(before), res, fill.repeat(after))
+        }
+    }
+
+    fn engineering(val: f64, precision: Option<usize>, upper: bool) -> String {
+        let e = if upper {'E'} else {'e'};
+
+        //NaN and the infinities have no exponent
+        if !val.is_finite() {
+            return val.to_string();
+        }
+
+        if val == 0.0 {
+            return match precision {
+                Some(p) => format!("{:.*}{}0", p, val, e),
+                None => format!("{}{}0", val, e),
+            };
+        }
+
+        let mut exp = val.abs().log10().floor() as i32;
+        exp -= exp.rem_euclid(3);
+
+        let mut mantissa = val / 10f64.powi(exp);
+
+        //rounding may carry the mantissa up to 1000
+        if let Some(p) = precision {
+            if format!("{:.*}", p, mantissa.abs()).starts_with("1000") {
+                exp += 3;
+                mantissa = val / 10f64.powi(exp);
+            }
+        }
+
+        match precision {
+            Some(p) => format!("{:.*}{}{}", p, mantissa, e, exp),
+            None => format!("{}{}{}", mantissa, e, exp),
+        }
+    }
+
+    /// Imaginary paired with the Format it is going to be displayed with
+    pub struct Formatted<'a> {
+        val: &'a Imaginary,
+        format: Format,
+    }
+
+    impl Display for Formatted<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            self.format.write(self.val, f)
+        }
+    }
+
+    impl Imaginary {
+        pub fn format(&self, format: Format) -> Formatted<'_> {
+            Formatted {val: self, format}
+        }
+    }
+
     impl Display for Imaginary {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "({} {} {}i)", self.real, if self.imaginary > 0.0 {"+"} else {"-"}, self.imaginary.abs())
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            Format::new().write(self, f)
+        }
+    }
+
+    impl LowerExp for Imaginary {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            Format::new().scientific().write(self, f)
+        }
+    }
+
+    impl UpperExp for Imaginary {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            Format::new().notation(Notation::Scientific {upper: true}).write(self, f)
         }
     }
 
@@ -215,6 +486,174 @@ pub mod imgn {
         }
     }
 
+    impl Neg for &Imaginary {
+        type Output = Imaginary;
+
+        fn neg(self) -> Self::Output {
+            -*self
+        }
+    }
+
+    impl AddAssign<f64> for Imaginary {
+        fn add_assign(&mut self, rhs: f64) {
+            self.real += rhs;
+        }
+    }
+
+    impl SubAssign<f64> for Imaginary {
+        fn sub_assign(&mut self, rhs: f64) {
+            self.real -= rhs;
+        }
+    }
+
+    impl MulAssign<f64> for Imaginary {
+        fn mul_assign(&mut self, rhs: f64) {
+            self.real *= rhs;
+            self.imaginary *= rhs;
+        }
+    }
+
+    impl DivAssign<f64> for Imaginary {
+        fn div_assign(&mut self, rhs: f64) {
+            self.real /= rhs;
+            self.imaginary /= rhs;
+        }
+    }
+
+    /// A macro for implementing the remaining owned/borrowed combinations of an operator
+    /// through its assigning version
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl $op_assign<&Imaginary> for Imaginary {
+                    fn $method_assign(&mut self, rhs: &Imaginary) {
+                        self.$method_assign(*rhs);
+                    }
+                }
+
+                impl $op_assign<&f64> for Imaginary {
+                    fn $method_assign(&mut self, rhs: &f64) {
+                        self.$method_assign(*rhs);
+                    }
+                }
+
+                impl $op<&Imaginary> for Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: &Imaginary) -> Self::Output {
+                        self.$method(*rhs)
+                    }
+                }
+
+                impl $op<Imaginary> for &Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: Imaginary) -> Self::Output {
+                        (*self).$method(rhs)
+                    }
+                }
+
+                impl $op<f64> for Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: f64) -> Self::Output {
+                        let mut res = self;
+                        res.$method_assign(rhs);
+
+                        res
+                    }
+                }
+
+                impl $op<&f64> for Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: &f64) -> Self::Output {
+                        self.$method(*rhs)
+                    }
+                }
+
+                impl $op<f64> for &Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: f64) -> Self::Output {
+                        (*self).$method(rhs)
+                    }
+                }
+
+                impl $op<&f64> for &Imaginary {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: &f64) -> Self::Output {
+                        (*self).$method(*rhs)
+                    }
+                }
+
+                impl $op<Imaginary> for f64 {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: Imaginary) -> Self::Output {
+                        self.to_imaginary().$method(rhs)
+                    }
+                }
+
+                impl $op<&Imaginary> for f64 {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: &Imaginary) -> Self::Output {
+                        self.to_imaginary().$method(*rhs)
+                    }
+                }
+
+                impl $op<Imaginary> for &f64 {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: Imaginary) -> Self::Output {
+                        self.to_imaginary().$method(rhs)
+                    }
+                }
+
+                impl $op<&Imaginary> for &f64 {
+                    type Output = Imaginary;
+
+                    fn $method(self, rhs: &Imaginary) -> Self::Output {
+                        self.to_imaginary().$method(*rhs)
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(
+        Add, add, AddAssign, add_assign;
+        Sub, sub, SubAssign, sub_assign;
+        Mul, mul, MulAssign, mul_assign;
+        Div, div, DivAssign, div_assign
+    );
+
+    impl Sum for Imaginary {
+        fn sum<I: Iterator<Item = Imaginary>>(iter: I) -> Self {
+            iter.fold(Imaginary::zero(), |acc, v| acc + v)
+        }
+    }
+
+    impl<'a> Sum<&'a Imaginary> for Imaginary {
+        fn sum<I: Iterator<Item = &'a Imaginary>>(iter: I) -> Self {
+            iter.fold(Imaginary::zero(), |acc, v| acc + v)
+        }
+    }
+
+    impl Product for Imaginary {
+        fn product<I: Iterator<Item = Imaginary>>(iter: I) -> Self {
+            iter.fold(Imaginary::one(), |acc, v| acc * v)
+        }
+    }
+
+    impl<'a> Product<&'a Imaginary> for Imaginary {
+        fn product<I: Iterator<Item = &'a Imaginary>>(iter: I) -> Self {
+            iter.fold(Imaginary::one(), |acc, v| acc * v)
+        }
+    }
+
     impl AssAdd for Imaginary {}
     impl ComAdd for Imaginary {}
     impl AssMul for Imaginary {}
@@ -233,6 +672,7 @@ pub mod imgn {
     }
 
     impl IntegralDomain for Imaginary {}
+    impl DivisionRing for Imaginary {}
     impl Field for Imaginary {}
 
     impl Meta for Imaginary {
@@ -245,7 +685,134 @@ pub mod imgn {
         }
     }
 
+    impl Magnitude for Imaginary {
+        fn magnitude(&self) -> f64 {
+            self.abs()
+        }
+    }
+
+    impl ApproxEq for Imaginary {
+        fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+            if self == other {
+                return true;
+            }
+
+            let diff = (self - other).abs();
+
+            if diff <= tol.abs || diff <= tol.rel * self.abs().max(other.abs()) {
+                return true;
+            }
+
+            let ulps = Tolerance::ulps(tol.ulps);
+
+            self.real.approx_eq(&other.real, ulps) && self.imaginary.approx_eq(&other.imaginary, ulps)
+        }
+    }
+
     //TODO
     //impl Gcd
 }
 
+pub mod riemann;
+
+#[cfg(test)]
+mod tests {
+    use crate::imgn::{Imaginary, Format, Notation, AngleUnit};
+    use tech::{Ring, UnRing};
+
+    #[test]
+    fn notation_and_form_matrix() {
+        let val = Imaginary::new(-1.0, 1.0);
+        let notations = [
+            Notation::Fixed,
+            Notation::Scientific {upper: false},
+            Notation::Scientific {upper: true},
+            Notation::Engineering {upper: false},
+        ];
+        let expected = [
+            ["(-1.00 + 1.00i)", "1.41∠135.00°", "1.41∠2.36"],
+            ["(-1.00e0 + 1.00e0i)", "1.41e0∠1.35e2°", "1.41e0∠2.36e0"],
+            ["(-1.00E0 + 1.00E0i)", "1.41E0∠1.35E2°", "1.41E0∠2.36E0"],
+            ["(-1.00e0 + 1.00e0i)", "1.41e0∠135.00e0°", "1.41e0∠2.36e0"],
+        ];
+
+        for (notation, row) in notations.into_iter().zip(expected) {
+            let format = Format::new().precision(2).notation(notation);
+
+            assert_eq!(val.format(format.cartesian()).to_string(), row[0]);
+            assert_eq!(val.format(format.polar(AngleUnit::Degrees)).to_string(), row[1]);
+            assert_eq!(val.format(format.polar(AngleUnit::Radians)).to_string(), row[2]);
+        }
+    }
+
+    #[test]
+    fn engineering_exponents() {
+        let format = Format::new().engineering().precision(2);
+
+        assert_eq!(Imaginary::new(12345.0, 0.5).format(format).to_string(), "(12.35e3 + 500.00e-3i)");
+        assert_eq!(Imaginary::new(999.999, 0.0).format(format).to_string(), "(1.00e3 + 0.00e0i)");
+    }
+
+    #[test]
+    fn engineering_non_finite() {
+        let format = Format::new().engineering();
+
+        assert_eq!(Imaginary::new(f64::NAN, f64::INFINITY).format(format).to_string(), "(NaN + infi)");
+        assert_eq!(Imaginary::new(1.0, f64::NEG_INFINITY).format(format).to_string(), "(1e0 - infi)");
+    }
+
+    #[test]
+    fn padding() {
+        let val = Imaginary::new(1.0, 2.0);
+
+        assert_eq!(format!("{:>20}", val), "            (1 + 2i)");
+        assert_eq!(format!("{:*^12}", val), "**(1 + 2i)**");
+        assert_eq!(format!("{:10}", val), "(1 + 2i)  ");
+        assert_eq!(format!("{:5.1}", val), "(1.0 + 2.0i)");
+        assert_eq!(format!("{:>4}", Imaginary::new(3.0, 0.0).format(Format::new().suppress_zero(true))), "   3");
+    }
+
+    #[test]
+    fn scalar_operands_on_either_side() {
+        let val = Imaginary::new(1.0, 2.0);
+
+        assert_eq!(val + 1.0, Imaginary::new(2.0, 2.0));
+        assert_eq!(1.0 - val, Imaginary::new(0.0, -2.0));
+        let (val_ref, two) = (&val, &2.0);
+
+        assert_eq!(val_ref * 2.0, Imaginary::new(2.0, 4.0));
+        assert_eq!(val * two, Imaginary::new(2.0, 4.0));
+        assert_eq!(two * val_ref, Imaginary::new(2.0, 4.0));
+        assert_eq!(val / 2.0, Imaginary::new(0.5, 1.0));
+        assert_eq!(5.0 / Imaginary::new(1.0, 2.0), Imaginary::new(1.0, -2.0));
+    }
+
+    #[test]
+    fn borrowed_operands_match_owned_ones() {
+        let a = Imaginary::new(1.0, 2.0);
+        let b = Imaginary::new(3.0, -1.0);
+        let (a_ref, b_ref) = (&a, &b);
+
+        assert_eq!(a + b_ref, a + b);
+        assert_eq!(a_ref - b, a - b);
+        assert_eq!(a_ref * b_ref, a * b);
+        assert_eq!(a / b_ref, a / b);
+
+        let mut c = a;
+        c *= b_ref;
+        c -= &1.0;
+        assert_eq!(c, a * b - 1.0);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let vals = [Imaginary::new(1.0, 1.0), Imaginary::new(2.0, -1.0), Imaginary::new(0.0, 3.0)];
+
+        assert_eq!(vals.iter().sum::<Imaginary>(), Imaginary::new(3.0, 3.0));
+        assert_eq!(vals.into_iter().sum::<Imaginary>(), Imaginary::new(3.0, 3.0));
+        assert_eq!(vals.iter().product::<Imaginary>(), Imaginary::new(-3.0, 9.0));
+        assert_eq!(vals.into_iter().product::<Imaginary>(), Imaginary::new(-3.0, 9.0));
+        assert_eq!(std::iter::empty::<Imaginary>().sum::<Imaginary>(), Imaginary::zero());
+        assert_eq!(std::iter::empty::<Imaginary>().product::<Imaginary>(), Imaginary::one());
+    }
+}
@@ -0,0 +1,372 @@
+//! Extended complex plane (the Riemann sphere) and Möbius transformations on it
+
+use crate::imgn::Imaginary;
+use tech::{Ring, UnRing};
+use std::{
+    fmt::Display,
+    ops::{Add, Sub, Mul, Div, Neg},
+};
+
+/// A point of the Riemann sphere: either a finite Imaginary or the point at infinity.
+///
+/// Division by zero is well defined: `z / 0 = ∞` and `z / ∞ = 0` for every finite `z != 0`.
+/// Indeterminate forms (`0 / 0`, `∞ / ∞`, `0 * ∞`, `∞ + ∞`, `∞ - ∞`) give a finite NaN, the same way f64 does
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtendedComplex {
+    Finite(Imaginary),
+    Infinity,
+}
+
+use ExtendedComplex::{Finite, Infinity};
+
+fn nan() -> ExtendedComplex {
+    Finite(Imaginary::new(f64::NAN, f64::NAN))
+}
+
+impl ExtendedComplex {
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, Infinity)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Finite(z) if z.is_zero())
+    }
+
+    /// Returns true for the result of an indeterminate form
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Finite(z) if z.real().is_nan() || z.imaginary().is_nan())
+    }
+
+    pub fn finite(self) -> Option<Imaginary> {
+        match self {
+            Finite(z) => Some(z),
+            Infinity => None,
+        }
+    }
+
+    /// `1 / z`, so that `0` and `∞` are swapped
+    pub fn recip(self) -> ExtendedComplex {
+        match self {
+            Infinity => Finite(Imaginary::zero()),
+            Finite(z) if z.is_zero() => Infinity,
+            Finite(z) => Finite(Imaginary::one() / z),
+        }
+    }
+
+    /// Stereographic projection onto the unit sphere, the point at infinity goes to the north pole `(0, 0, 1)`
+    pub fn to_sphere(self) -> (f64, f64, f64) {
+        match self {
+            Infinity => (0.0, 0.0, 1.0),
+            Finite(z) => {
+                let (x, y) = (z.real(), z.imaginary());
+                let n = x * x + y * y;
+
+                (2.0 * x / (n + 1.0), 2.0 * y / (n + 1.0), (n - 1.0) / (n + 1.0))
+            }
+        }
+    }
+
+    /// Inverse of the stereographic projection. The point is assumed to lie on the unit sphere
+    pub fn from_sphere(x: f64, y: f64, z: f64) -> ExtendedComplex {
+        if z == 1.0 {
+            return Infinity;
+        }
+
+        Finite(Imaginary::new(x / (1.0 - z), y / (1.0 - z)))
+    }
+}
+
+impl From<Imaginary> for ExtendedComplex {
+    fn from(val: Imaginary) -> Self {
+        Finite(val)
+    }
+}
+
+impl Display for ExtendedComplex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finite(z) => z.fmt(f),
+            Infinity => write!(f, "∞"),
+        }
+    }
+}
+
+impl Add for ExtendedComplex {
+    type Output = ExtendedComplex;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Finite(l + r),
+            (Infinity, Infinity) => nan(),
+            _ => Infinity,
+        }
+    }
+}
+
+impl Sub for ExtendedComplex {
+    type Output = ExtendedComplex;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Finite(l - r),
+            (Infinity, Infinity) => nan(),
+            _ => Infinity,
+        }
+    }
+}
+
+impl Mul for ExtendedComplex {
+    type Output = ExtendedComplex;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Finite(l * r),
+            (Finite(z), Infinity) | (Infinity, Finite(z)) if z.is_zero() => nan(),
+            _ => Infinity,
+        }
+    }
+}
+
+impl Div for ExtendedComplex {
+    type Output = ExtendedComplex;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Infinity, Infinity) => nan(),
+            (Finite(l), Finite(r)) if r.is_zero() => if l.is_zero() {nan()} else {Infinity},
+            (Finite(l), Finite(r)) => Finite(l / r),
+            (Finite(_), Infinity) => Finite(Imaginary::zero()),
+            (Infinity, Finite(_)) => Infinity,
+        }
+    }
+}
+
+impl Neg for ExtendedComplex {
+    type Output = ExtendedComplex;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Finite(z) => Finite(-z),
+            Infinity => Infinity,
+        }
+    }
+}
+
+/// Fixed points of a Möbius transformation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FixedPoints {
+    /// The transformation is the identity
+    All,
+    One(ExtendedComplex),
+    Two(ExtendedComplex, ExtendedComplex),
+}
+
+/// Möbius transformation `z -> (az + b)/(cz + d)` with `ad - bc != 0`.
+///
+/// The coefficients are only defined up to a common non-zero factor, so
+/// PartialEq compares transformations rather than coefficients
+#[derive(Debug, Clone, Copy)]
+pub struct Mobius {
+    a: Imaginary,
+    b: Imaginary,
+    c: Imaginary,
+    d: Imaginary,
+}
+
+impl Mobius {
+    pub fn new(a: Imaginary, b: Imaginary, c: Imaginary, d: Imaginary) -> Mobius {
+        if (a * d - b * c).is_zero() {
+            panic!("Creating a Mobius transformation with zero determinant");
+        }
+
+        Mobius {a, b, c, d}
+    }
+
+    pub fn identity() -> Mobius {
+        Mobius {a: Imaginary::one(), b: Imaginary::zero(), c: Imaginary::zero(), d: Imaginary::one()}
+    }
+
+    /// The unique transformation that maps `from[i]` to `to[i]`.
+    /// Returns None if the points in one of the triples are not distinct
+    pub fn from_points(from: [ExtendedComplex; 3], to: [ExtendedComplex; 3]) -> Option<Mobius> {
+        let z = Mobius::to_zero_one_infinity(from)?;
+        let w = Mobius::to_zero_one_infinity(to)?;
+
+        Some(w.inverse().compose(&z))
+    }
+
+    /// The transformation that maps `z1, z2, z3` to `0, 1, ∞`
+    fn to_zero_one_infinity([z1, z2, z3]: [ExtendedComplex; 3]) -> Option<Mobius> {
+        let one = Imaginary::one();
+        let zero = Imaginary::zero();
+
+        let (a, b, c, d) = match (z1, z2, z3) {
+            (Finite(z1), Finite(z2), Finite(z3)) => (z2 - z3, -(z1 * (z2 - z3)), z2 - z1, -(z3 * (z2 - z1))),
+            (Infinity, Finite(z2), Finite(z3)) => (zero, z2 - z3, one, -z3),
+            (Finite(z1), Infinity, Finite(z3)) => (one, -z1, one, -z3),
+            (Finite(z1), Finite(z2), Infinity) => (one, -z1, zero, z2 - z1),
+            _ => return None,
+        };
+
+        if (a * d - b * c).is_zero() {
+            return None;
+        }
+
+        Some(Mobius {a, b, c, d})
+    }
+
+    pub fn from_matrix(m: [[Imaginary; 2]; 2]) -> Mobius {
+        Mobius::new(m[0][0], m[0][1], m[1][0], m[1][1])
+    }
+
+    pub fn to_matrix(&self) -> [[Imaginary; 2]; 2] {
+        [[self.a, self.b], [self.c, self.d]]
+    }
+
+    pub fn determinant(&self) -> Imaginary {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Scales the coefficients so that the determinant equals one
+    pub fn normalized(&self) -> Mobius {
+        let k = self.determinant().root(2)[0];
+
+        Mobius {a: self.a / k, b: self.b / k, c: self.c / k, d: self.d / k}
+    }
+
+    pub fn apply(&self, z: ExtendedComplex) -> ExtendedComplex {
+        match z {
+            Infinity if self.c.is_zero() => Infinity,
+            Infinity => Finite(self.a / self.c),
+            Finite(z) => {
+                let denom = self.c * z + self.d;
+
+                if denom.is_zero() {
+                    return Infinity;
+                }
+
+                Finite((self.a * z + self.b) / denom)
+            }
+        }
+    }
+
+    /// `self ∘ rhs`, the transformation that applies `rhs` first
+    pub fn compose(&self, rhs: &Mobius) -> Mobius {
+        Mobius {
+            a: self.a * rhs.a + self.b * rhs.c,
+            b: self.a * rhs.b + self.b * rhs.d,
+            c: self.c * rhs.a + self.d * rhs.c,
+            d: self.c * rhs.b + self.d * rhs.d,
+        }
+    }
+
+    pub fn inverse(&self) -> Mobius {
+        Mobius {a: self.d, b: -self.b, c: -self.c, d: self.a}
+    }
+
+    pub fn fixed_points(&self) -> FixedPoints {
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+
+        //cz^2 + (d - a)z - b = 0
+        if c.is_zero() {
+            if (a - d).is_zero() {
+                return if b.is_zero() {FixedPoints::All} else {FixedPoints::One(Infinity)};
+            }
+
+            return FixedPoints::Two(Finite(b / (d - a)), Infinity);
+        }
+
+        let discriminant = (a - d) * (a - d) + 4.0 * b * c;
+
+        if discriminant.is_zero() {
+            return FixedPoints::One(Finite((a - d) / (2.0 * c)));
+        }
+
+        let root = discriminant.root(2)[0];
+
+        FixedPoints::Two(Finite((a - d + root) / (2.0 * c)), Finite((a - d - root) / (2.0 * c)))
+    }
+}
+
+impl PartialEq for Mobius {
+    fn eq(&self, other: &Self) -> bool {
+        let l = [self.a, self.b, self.c, self.d];
+        let r = [other.a, other.b, other.c, other.d];
+
+        //the coefficients have to be proportional
+        (0..4).all(|i| (i + 1..4).all(|j| l[i] * r[j] == l[j] * r[i]))
+    }
+}
+
+impl Mul for Mobius {
+    type Output = Mobius;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl Display for Mobius {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}z + {})/({}z + {})", self.a, self.b, self.c, self.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tech::{ApproxEq, Tolerance};
+
+    fn close(l: ExtendedComplex, r: ExtendedComplex) -> bool {
+        match (l, r) {
+            (Finite(l), Finite(r)) => l.approx_eq(&r, Tolerance::default()),
+            (l, r) => l == r,
+        }
+    }
+
+    #[test]
+    fn from_points_maps_each_point() {
+        let from = [Finite(Imaginary::zero()), Finite(Imaginary::one()), Infinity];
+        let to = [Finite(Imaginary::new(1.0, 0.0)), Finite(Imaginary::new(0.0, 1.0)), Finite(Imaginary::new(-1.0, 0.0))];
+        let m = Mobius::from_points(from, to).unwrap();
+
+        for (z, w) in from.into_iter().zip(to) {
+            assert!(close(m.apply(z), w), "{} maps to {}, expected {}", z, m.apply(z), w);
+        }
+
+        let repeated = [Finite(Imaginary::one()), Finite(Imaginary::one()), Infinity];
+
+        assert!(Mobius::from_points(repeated, to).is_none());
+        assert!(Mobius::from_points(from, [Infinity, Infinity, Finite(Imaginary::zero())]).is_none());
+    }
+
+    #[test]
+    fn inverse_and_compose() {
+        let shift = Mobius::new(Imaginary::one(), Imaginary::one(), Imaginary::zero(), Imaginary::one());
+        let double = Mobius::new(Imaginary::new(2.0, 0.0), Imaginary::zero(), Imaginary::zero(), Imaginary::one());
+        let one = Finite(Imaginary::one());
+
+        //compose applies the right hand side first
+        assert_eq!(shift.compose(&double).apply(one), Finite(Imaginary::new(3.0, 0.0)));
+        assert_eq!((double * shift).apply(one), Finite(Imaginary::new(4.0, 0.0)));
+
+        let m = Mobius::new(Imaginary::new(1.0, 1.0), Imaginary::new(2.0, 0.0), Imaginary::one(), Imaginary::new(-1.0, 0.0));
+
+        assert_eq!(m * m.inverse(), Mobius::identity());
+        assert_eq!(m.inverse() * m, Mobius::identity());
+        assert!(close(m.inverse().apply(m.apply(Finite(Imaginary::new(0.5, -2.0)))), Finite(Imaginary::new(0.5, -2.0))));
+        assert_eq!(m.inverse().apply(m.apply(Infinity)), Infinity);
+    }
+
+    #[test]
+    fn fixed_points() {
+        let zero = Imaginary::zero();
+        let one = Imaginary::one();
+
+        assert_eq!(Mobius::identity().fixed_points(), FixedPoints::All);
+        assert_eq!(Mobius::new(one, one, zero, one).fixed_points(), FixedPoints::One(Infinity));
+        assert_eq!(Mobius::new(Imaginary::new(2.0, 0.0), zero, zero, one).fixed_points(), FixedPoints::Two(Finite(zero), Infinity));
+        assert_eq!(Mobius::new(one, zero, one, one).fixed_points(), FixedPoints::One(Finite(zero)));
+        assert_eq!(Mobius::new(zero, one, one, zero).fixed_points(), FixedPoints::Two(Finite(one), Finite(-one)));
+    }
+}
@@ -0,0 +1,68 @@
+//! Types that can bound an Interval and how their results are rounded
+
+use tech::{UnRing, IntegralDomain, Meta, Gcd, OrderedRing};
+use fraction::fract::{Fraction, Reducible, Rational, Policy};
+use std::ops::{Mul, DivAssign};
+
+/// The bounds of an Interval. Every operation on the bounds is computed as usual and then widened
+/// by `down` for the lower bound and `up` for the upper one, so the exact result stays inside
+pub trait Endpoint: UnRing + PartialOrd + Clone {
+    /// A value not greater than the exact result that x was rounded from
+    fn down(x: Self) -> Self;
+    /// A value not less than the exact result that x was rounded from
+    fn up(x: Self) -> Self;
+}
+
+/// A macro for implementing Endpoint for the float types,
+/// a float rounded to the nearest is at most half an ulp away from the exact value
+macro_rules! implEndpoint {
+    ($($typ: ty),*) => {
+        $(
+            impl Endpoint for $typ {
+                fn down(x: $typ) -> $typ {
+                    x.next_down()
+                }
+
+                fn up(x: $typ) -> $typ {
+                    x.next_up()
+                }
+            }
+        )*
+    };
+}
+
+//primitive integers are no Endpoint: they overflow instead of rounding, so the bounds would not enclose the result
+implEndpoint!(f32, f64);
+
+impl<T: IntegralDomain + Meta + OrderedRing + Clone> Endpoint for Fraction<T>
+where for <'a> &'a T: Mul<&'a T, Output = T> {
+    fn down(x: Self) -> Self {
+        x
+    }
+
+    fn up(x: Self) -> Self {
+        x
+    }
+}
+
+impl<T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing + Clone, P: Policy<T> + Clone> Endpoint for Reducible<T, P>
+where for <'a> &'a T: Mul<&'a T, Output = T> {
+    fn down(x: Self) -> Self {
+        x
+    }
+
+    fn up(x: Self) -> Self {
+        x
+    }
+}
+
+impl<T: IntegralDomain + Meta + Gcd + DivAssign<T> + OrderedRing + Clone> Endpoint for Rational<T>
+where for <'a> &'a T: Mul<&'a T, Output = T> {
+    fn down(x: Self) -> Self {
+        x
+    }
+
+    fn up(x: Self) -> Self {
+        x
+    }
+}
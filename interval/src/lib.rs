@@ -0,0 +1,404 @@
+//! # interval
+//!
+//! Closed intervals with rigorous bounds: the result of every operation contains the results for all values
+//! of the operands. Float bounds are rounded outward, bounds from exact types like Reducible are exact
+
+pub mod intrvl {
+    use crate::endpoint::Endpoint;
+    use tech::{Ring, UnRing, Field, Meta, AssAdd, ComAdd, AssMul, ComMul, MathError};
+    use polynomial::any_pnm::Polynomial;
+    use std::{
+        ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+        fmt::Display,
+    };
+
+    /// `[lo, hi]`, the bounds of float intervals are expected to be finite
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Interval<T: Endpoint> {
+        lo: T,
+        hi: T,
+    }
+
+    fn min<T: PartialOrd>(a: T, b: T) -> T {
+        if b < a {b} else {a}
+    }
+
+    fn max<T: PartialOrd>(a: T, b: T) -> T {
+        if b > a {b} else {a}
+    }
+
+    impl<T: Endpoint> Interval<T> {
+        /// Panics if lo > hi or the bounds are not comparable
+        pub fn new(lo: T, hi: T) -> Interval<T> {
+            if lo.partial_cmp(&hi).is_none_or(|ord| ord.is_gt()) {
+                panic!("Creating an Interval with lo > hi");
+            }
+
+            Interval {lo, hi}
+        }
+
+        /// The interval `[x, x]`
+        pub fn point(x: T) -> Interval<T> {
+            Interval {lo: x.clone(), hi: x}
+        }
+
+        pub fn lo(&self) -> &T {
+            &self.lo
+        }
+
+        pub fn hi(&self) -> &T {
+            &self.hi
+        }
+
+        /// An upper bound of `hi - lo`
+        pub fn width(&self) -> T {
+            T::up(self.hi.clone() - self.lo.clone())
+        }
+
+        pub fn contains(&self, x: &T) -> bool {
+            &self.lo <= x && x <= &self.hi
+        }
+
+        pub fn contains_zero(&self) -> bool {
+            self.contains(&T::zero())
+        }
+
+        /// Whether rhs is a subset of self
+        pub fn contains_interval(&self, rhs: &Interval<T>) -> bool {
+            self.lo <= rhs.lo && rhs.hi <= self.hi
+        }
+
+        /// The smallest interval that contains both
+        pub fn hull(&self, rhs: &Interval<T>) -> Interval<T> {
+            Interval {lo: min(self.lo.clone(), rhs.lo.clone()), hi: max(self.hi.clone(), rhs.hi.clone())}
+        }
+
+        /// None if the intervals do not overlap
+        pub fn intersection(&self, rhs: &Interval<T>) -> Option<Interval<T>> {
+            let lo = max(self.lo.clone(), rhs.lo.clone());
+            let hi = min(self.hi.clone(), rhs.hi.clone());
+
+            if lo <= hi {Some(Interval {lo, hi})} else {None}
+        }
+
+        /// `{|x| : x in self}`
+        pub fn abs(&self) -> Interval<T> {
+            if self.lo >= T::zero() {
+                self.clone()
+            } else if self.hi <= T::zero() {
+                -self
+            } else {
+                Interval {lo: T::zero(), hi: max(T::zero() - self.lo.clone(), self.hi.clone())}
+            }
+        }
+
+        /// `{x^n : x in self}`, tighter than multiplying self n times since the factors are not independent
+        pub fn powi(&self, n: u32) -> Interval<T> {
+            if n == 0 {
+                return Interval::one();
+            }
+
+            //x^n is monotone on the non-negative numbers and for odd n on all numbers
+            let pow = |x: &T| (1..n).fold(Interval::point(x.clone()), |acc, _| &acc * &Interval::point(x.clone()));
+
+            if n % 2 == 1 {
+                return Interval {lo: pow(&self.lo).lo, hi: pow(&self.hi).hi};
+            }
+
+            let abs = self.abs();
+
+            Interval {lo: max(pow(&abs.lo).lo, T::zero()), hi: pow(&abs.hi).hi}
+        }
+
+        /// An enclosure of the range of p over self by Horner's scheme
+        pub fn eval_polynomial(&self, p: &Polynomial<T>) -> Interval<T>
+        where T: Field {
+            p.eval_in(self)
+        }
+
+        /// The hull of `eval_polynomial` over `pieces` equal parts of self,
+        /// closer to the exact range of p as the number of pieces grows. Panics for zero pieces
+        pub fn polynomial_range(&self, p: &Polynomial<T>, pieces: usize) -> Interval<T>
+        where T: Field {
+            if pieces == 0 {
+                panic!("Splitting an Interval into zero pieces");
+            }
+
+            let n = (0..pieces).fold(T::zero(), |acc, _| acc + T::one());
+            let step = self.hi.clone() - self.lo.clone();
+
+            //rounding is monotone, so the break points are ordered and the pieces cover self
+            let mut k = T::zero();
+            let mut lo = self.lo.clone();
+            let mut res: Option<Interval<T>> = None;
+
+            for i in 1..=pieces {
+                k += T::one();
+
+                let hi = if i == pieces {self.hi.clone()} else {self.lo.clone() + step.clone() * k.clone() / n.clone()};
+                let piece = Interval {lo, hi: hi.clone()}.eval_polynomial(p);
+
+                res = Some(match res {
+                    Some(range) => range.hull(&piece),
+                    None => piece,
+                });
+                lo = hi;
+            }
+
+            res.unwrap()
+        }
+    }
+
+    impl<T: Endpoint + Field> Interval<T> {
+        /// `(lo + hi) / 2`, rounded as T rounds
+        pub fn midpoint(&self) -> T {
+            (self.lo.clone() + self.hi.clone()) / (T::one() + T::one())
+        }
+
+        /// Fails with NonInvertible if the interval contains zero
+        pub fn inverse(&self) -> Result<Interval<T>, MathError> {
+            if self.contains_zero() {
+                return Err(MathError::NonInvertible);
+            }
+
+            Ok(Interval {lo: T::down(T::one() / self.hi.clone()), hi: T::up(T::one() / self.lo.clone())})
+        }
+    }
+
+    impl<T: Endpoint> From<T> for Interval<T> {
+        fn from(x: T) -> Self {
+            Interval::point(x)
+        }
+    }
+
+    /// `a + b` rounded by `round` unless one of them is zero and the sum is exact
+    fn sum<T: Endpoint>(a: T, b: T, round: fn(T) -> T) -> T {
+        if a.is_zero() || b.is_zero() {a + b} else {round(a + b)}
+    }
+
+    impl<T: Endpoint> Add for &Interval<T> {
+        type Output = Interval<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Interval {lo: sum(self.lo.clone(), rhs.lo.clone(), T::down), hi: sum(self.hi.clone(), rhs.hi.clone(), T::up)}
+        }
+    }
+
+    impl<T: Endpoint> Sub for &Interval<T> {
+        type Output = Interval<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self + &(-rhs)
+        }
+    }
+
+    /// The smallest and the largest of `op` applied to the bounds of lhs and rhs,
+    /// results that `exact` recognizes as exact zeros are not widened
+    fn combine<T: Endpoint>(lhs: &Interval<T>, rhs: &Interval<T>, op: impl Fn(T, T) -> T, exact: impl Fn(&T, &T) -> bool) -> Interval<T> {
+        let bounds = |a: &T, b: &T| {
+            if exact(a, b) {
+                return (T::zero(), T::zero());
+            }
+
+            let res = op(a.clone(), b.clone());
+
+            (T::down(res.clone()), T::up(res))
+        };
+
+        let [a, b, c, d] = [
+            bounds(&lhs.lo, &rhs.lo),
+            bounds(&lhs.lo, &rhs.hi),
+            bounds(&lhs.hi, &rhs.lo),
+            bounds(&lhs.hi, &rhs.hi),
+        ];
+
+        Interval {lo: min(min(a.0, b.0), min(c.0, d.0)), hi: max(max(a.1, b.1), max(c.1, d.1))}
+    }
+
+    impl<T: Endpoint> Mul for &Interval<T> {
+        type Output = Interval<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            combine(self, rhs, |a, b| a * b, |a, b| a.is_zero() || b.is_zero())
+        }
+    }
+
+    /// Panics if rhs contains zero
+    impl<T: Endpoint + Field> Div for &Interval<T> {
+        type Output = Interval<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            if rhs.contains_zero() {
+                panic!("dividing by an Interval containing zero");
+            }
+
+            combine(self, rhs, |a, b| a / b, |a, _| a.is_zero())
+        }
+    }
+
+    /// A macro for implementing the owned and assigning versions of an operator through the borrowed one
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl<T: Endpoint> $op for Interval<T> {
+                    type Output = Interval<T>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        (&self).$method(&rhs)
+                    }
+                }
+
+                impl<T: Endpoint> $op_assign for Interval<T> {
+                    fn $method_assign(&mut self, rhs: Self) {
+                        *self = (&*self).$method(&rhs);
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+    impl<T: Endpoint + Field> Div for Interval<T> {
+        type Output = Interval<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            &self / &rhs
+        }
+    }
+
+    impl<T: Endpoint + Field> DivAssign for Interval<T> {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = &*self / &rhs;
+        }
+    }
+
+    //negating is exact for every Endpoint
+    impl<T: Endpoint> Neg for Interval<T> {
+        type Output = Interval<T>;
+
+        fn neg(self) -> Self::Output {
+            -&self
+        }
+    }
+
+    impl<T: Endpoint> Neg for &Interval<T> {
+        type Output = Interval<T>;
+
+        fn neg(self) -> Self::Output {
+            Interval {lo: T::zero() - self.hi.clone(), hi: T::zero() - self.lo.clone()}
+        }
+    }
+
+    impl<T: Endpoint + Display> Display for Interval<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "[{}, {}]", self.lo, self.hi)
+        }
+    }
+
+    //x - x is not zero and multiplying only distributes into a superset, so Interval is a Ring in the sense
+    //of the operations and not of the axioms, like the floats themselves
+    impl<T: Endpoint> AssAdd for Interval<T> {}
+    impl<T: Endpoint> ComAdd for Interval<T> {}
+    impl<T: Endpoint> AssMul for Interval<T> {}
+    impl<T: Endpoint> ComMul for Interval<T> {}
+
+    impl<T: Endpoint> Ring for Interval<T> {
+        fn zero() -> Self {
+            Interval::point(T::zero())
+        }
+    }
+
+    impl<T: Endpoint> UnRing for Interval<T> {
+        fn one() -> Self {
+            Interval::point(T::one())
+        }
+    }
+
+    impl<T: Endpoint + Meta> Meta for Interval<T> {
+        fn non_zero() -> Self {
+            Interval::one()
+        }
+
+        fn name() -> String {
+            format!("Interval<{}>", T::name())
+        }
+    }
+}
+
+pub mod endpoint;
+
+#[cfg(test)]
+mod tests {
+    use crate::intrvl::Interval;
+    use fraction::fract::Rational;
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::MathError;
+
+    #[test]
+    fn sum_encloses_the_exact_result() {
+        let sum = Interval::point(0.1) + Interval::point(0.2);
+
+        assert!(sum.contains(&0.3));
+        assert!(sum.contains(&(0.1 + 0.2)));
+        assert!(*sum.lo() < *sum.hi());
+    }
+
+    #[test]
+    fn range_of_polynomial() {
+        //x^2 - x takes the values [-1/4, 0] on [0, 1]
+        let p: Polynomial<f64> = polynom![1.0, -1.0, 0.0];
+        let x = Interval::new(0.0, 1.0);
+        let exact = Interval::new(-0.25, 0.0);
+
+        let horner = x.eval_polynomial(&p);
+        assert!(horner.contains_interval(&exact));
+
+        let range = x.polynomial_range(&p, 100);
+        assert!(range.contains_interval(&exact));
+        assert!(*range.lo() > -0.26 && horner.contains_interval(&range));
+
+        //exact bounds give the enclosure without widening
+        let r = |n: i64, d: i64| Rational::new(n, d);
+        let p: Polynomial<Rational<i64>> = polynom![r(1, 1), r(-1, 1), r(0, 1)];
+        let range = Interval::new(r(0, 1), r(1, 1)).polynomial_range(&p, 2);
+
+        assert_eq!(range, Interval::new(r(-1, 2), r(0, 1)));
+        assert!(range.contains_interval(&Interval::new(r(-1, 4), r(0, 1))));
+    }
+
+    #[test]
+    fn exact_bounds() {
+        let r = |n: i64, d: i64| Rational::new(n, d);
+        let a = Interval::new(r(1, 3), r(1, 2));
+        let b = Interval::new(r(-2, 1), r(3, 1));
+
+        assert_eq!(a.clone() * b.clone(), Interval::new(r(-1, 1), r(3, 2)));
+        assert_eq!(a.clone() - a.clone(), Interval::new(r(-1, 6), r(1, 6)));
+        assert_eq!(a.inverse(), Ok(Interval::new(r(2, 1), r(3, 1))));
+        assert_eq!(a.midpoint(), r(5, 12));
+    }
+
+    #[test]
+    fn powers_and_sets() {
+        let x = Interval::new(-2.0, 1.0);
+
+        //the float bounds are widened even if the result is exact
+        assert!(x.powi(2).contains_interval(&Interval::new(0.0, 4.0)));
+        assert_eq!(*x.powi(2).lo(), 0.0);
+        assert!(x.powi(3).contains_interval(&Interval::new(-8.0, 1.0)));
+        assert_eq!(x.abs(), Interval::new(0.0, 2.0));
+        assert_eq!(x.hull(&Interval::point(3.0)), Interval::new(-2.0, 3.0));
+        assert_eq!(x.intersection(&Interval::new(0.5, 5.0)), Some(Interval::new(0.5, 1.0)));
+        assert_eq!(x.intersection(&Interval::point(1.5)), None);
+        assert_eq!(x.to_string(), "[-2, 1]");
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let x = Interval::new(-1.0, 1.0);
+
+        assert_eq!(x.inverse(), Err(MathError::NonInvertible));
+        assert!(std::panic::catch_unwind(|| Interval::point(1.0) / x).is_err());
+    }
+}
@@ -0,0 +1,456 @@
+//! Characteristic and minimal polynomials, companion matrices, polynomials of matrices
+//! and eigenvalues of f64 matrices
+
+use crate::mtrx::Matrix;
+use tech::{UnRing, ComMul, Field, MathError};
+use polynomial::any_pnm::Polynomial;
+use imaginary::imgn::Imaginary;
+
+impl<T: UnRing + ComMul + Clone> Matrix<T> {
+    /// Ratios of `det(xI - self)` from the highest power down, as taken by Polynomial::new.
+    /// Berkowitz's algorithm does not divide, so any commutative ring works. Panics if the matrix is not square
+    pub fn charpoly_ratios(&self) -> Vec<T> {
+        if !self.is_square() {
+            panic!("Characteristic polynomial of a {}x{} Matrix", self.rows(), self.cols());
+        }
+
+        let mut res = vec![T::one()];
+
+        //the characteristic polynomial of the leading k+1 x k+1 submatrix is a Toeplitz matrix times the previous one
+        for k in 0..self.rows() {
+            let row: Vec<T> = (0..k).map(|j| self[(k, j)].clone()).collect();
+            let mut col: Vec<T> = (0..k).map(|i| self[(i, k)].clone()).collect();
+
+            let mut toeplitz = vec![T::one(), T::zero() - self[(k, k)].clone()];
+
+            //-row * A^i * col for the leading k x k submatrix A
+            for _ in 0..k {
+                let val = row.iter().zip(col.iter()).fold(T::zero(), |acc, (r, c)| acc + r.clone() * c.clone());
+
+                toeplitz.push(T::zero() - val);
+
+                col = (0..k).map(|i| {
+                    (0..k).fold(T::zero(), |acc, j| acc + self[(i, j)].clone() * col[j].clone())
+                }).collect();
+            }
+
+            res = (0..k + 2).map(|i| {
+                (i.saturating_sub(k + 1)..=i.min(k)).fold(T::zero(), |acc, j| acc + toeplitz[i - j].clone() * res[j].clone())
+            }).collect();
+        }
+
+        res
+    }
+}
+
+impl<T: Field + Clone> Matrix<T> {
+    /// `det(xI - self)`, panics if the matrix is not square
+    pub fn charpoly(&self) -> Polynomial<T> {
+        Polynomial::new(self.charpoly_ratios())
+    }
+
+    /// The monic polynomial of the lowest degree that vanishes at the matrix.
+    /// Exact elimination is used, so floating point entries give unreliable results. Panics if the matrix is not square
+    pub fn minimal_polynomial(&self) -> Polynomial<T> {
+        if !self.is_square() {
+            panic!("Minimal polynomial of a {}x{} Matrix", self.rows(), self.cols());
+        }
+
+        let n = self.rows();
+
+        //the empty matrix is annihilated by the constant 1
+        if n == 0 {
+            return Polynomial::new(vec![T::one()]);
+        }
+
+        let mut powers = vec![Matrix::identity(n)];
+
+        //the first power that is a linear combination of the lower ones gives the polynomial
+        loop {
+            let next = powers.last().unwrap() * self;
+            powers.push(next);
+
+            let k = powers.len();
+            let system = Matrix::from_fn(n * n, k, |i, j| powers[j].data()[i].clone());
+
+            if let Some(v) = system.null_space().pop() {
+                let lead = v[k - 1].clone();
+
+                return Polynomial::new(v.into_iter().rev().map(|x| x / lead.clone()).collect());
+            }
+        }
+    }
+
+    /// Frobenius companion matrix of p: ones below the diagonal and the negated ratios of the monic p in the last column,
+    /// so that its characteristic polynomial is p up to a constant factor. Panics if p is a constant
+    pub fn companion(p: &Polynomial<T>) -> Matrix<T> {
+        let n = p.deg();
+
+        if n == 0 {
+            panic!("Companion matrix of a constant polynomial");
+        }
+
+        let ratios = p.get();
+        let lead = p.lead();
+
+        Matrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                T::zero() - ratios[i].clone() / lead.clone()
+            } else if i == j + 1 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// `p(self)` by Horner's scheme, `self.eval_polynomial(&self.charpoly())` is zero by Cayley–Hamilton.
+    /// Panics if the matrix is not square
+    pub fn eval_polynomial(&self, p: &Polynomial<T>) -> Matrix<T> {
+        if !self.is_square() {
+            panic!("Polynomial of a {}x{} Matrix", self.rows(), self.cols());
+        }
+
+        let n = self.rows();
+        let mut res = Matrix::zero(n, n);
+
+        for ratio in p.get().iter().rev() {
+            res = &res * self;
+
+            for i in 0..n {
+                res[(i, i)] += ratio.clone();
+            }
+        }
+
+        res
+    }
+}
+
+/// The Householder reflector `I - beta v v^T` that maps x onto a multiple of the first unit vector,
+/// v[0] is one. None if x is zero and nothing has to be done
+fn reflector(x: &[f64]) -> Option<(Vec<f64>, f64)> {
+    let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    if norm == 0.0 {
+        return None;
+    }
+
+    //the sign avoids cancellation in v[0]
+    let alpha = -norm.copysign(x[0]);
+    let head = x[0] - alpha;
+
+    let mut v: Vec<f64> = x.iter().map(|val| val / head).collect();
+    v[0] = 1.0;
+
+    let beta = 2.0 / v.iter().map(|val| val * val).sum::<f64>();
+
+    Some((v, beta))
+}
+
+/// Applies the reflector from the left to the rows starting at `row` and the columns in `cols`
+fn reflect_rows(a: &mut Matrix<f64>, (v, beta): &(Vec<f64>, f64), row: usize, cols: std::ops::RangeInclusive<usize>) {
+    for j in cols {
+        let dot: f64 = v.iter().enumerate().map(|(i, val)| val * a[(row + i, j)]).sum();
+
+        for (i, val) in v.iter().enumerate() {
+            a[(row + i, j)] -= beta * dot * val;
+        }
+    }
+}
+
+/// Applies the reflector from the right to the rows in `rows` and the columns starting at `col`
+fn reflect_cols(a: &mut Matrix<f64>, (v, beta): &(Vec<f64>, f64), rows: std::ops::RangeInclusive<usize>, col: usize) {
+    for i in rows {
+        let dot: f64 = v.iter().enumerate().map(|(j, val)| a[(i, col + j)] * val).sum();
+
+        for (j, val) in v.iter().enumerate() {
+            a[(i, col + j)] -= beta * dot * val;
+        }
+    }
+}
+
+/// Reduction to upper Hessenberg form by Householder similarity transforms, the eigenvalues do not change
+fn hessenberg(a: &mut Matrix<f64>) {
+    let n = a.rows();
+
+    for k in 0..n.saturating_sub(2) {
+        let col: Vec<f64> = (k + 1..n).map(|i| a[(i, k)]).collect();
+
+        if let Some(p) = reflector(&col) {
+            reflect_rows(a, &p, k + 1, k..=n - 1);
+            reflect_cols(a, &p, 0..=n - 1, k + 1);
+        }
+
+        for i in k + 2..n {
+            a[(i, k)] = 0.0;
+        }
+    }
+}
+
+/// Eigenvalues of the 2x2 block at (k, k)
+fn block_eigenvalues(h: &Matrix<f64>, k: usize) -> [Imaginary; 2] {
+    let (a, b, c, d) = (h[(k, k)], h[(k, k + 1)], h[(k + 1, k)], h[(k + 1, k + 1)]);
+
+    //the eigenvalues are d + p ± sqrt(p^2 + bc)
+    let p = 0.5 * (a - d);
+    let disc = p * p + b * c;
+
+    if disc >= 0.0 {
+        //the larger root first, the smaller one from the product of the roots to avoid cancellation
+        let r = p + disc.sqrt().copysign(p);
+        let other = if r != 0.0 {d - b * c / r} else {d};
+
+        [Imaginary::new(d + r, 0.0), Imaginary::new(other, 0.0)]
+    } else {
+        let im = (-disc).sqrt();
+
+        [Imaginary::new(d + p, im), Imaginary::new(d + p, -im)]
+    }
+}
+
+/// One Francis double shift step on the rows and columns lo..=hi of the Hessenberg matrix h (hi >= lo + 2).
+/// The shifts are the roots of `x^2 - trace x + det`, the bulge is chased down by 3x3 reflectors
+fn francis_step(h: &mut Matrix<f64>, lo: usize, hi: usize, trace: f64, det: f64) {
+    //the first column of (H - s1 I)(H - s2 I), it has three non-zero entries
+    let mut x = h[(lo, lo)] * h[(lo, lo)] + h[(lo, lo + 1)] * h[(lo + 1, lo)] - trace * h[(lo, lo)] + det;
+    let mut y = h[(lo + 1, lo)] * (h[(lo, lo)] + h[(lo + 1, lo + 1)] - trace);
+    let mut z = h[(lo + 1, lo)] * h[(lo + 2, lo + 1)];
+
+    for k in lo..hi - 1 {
+        if let Some(p) = reflector(&[x, y, z]) {
+            let first = if k > lo {k - 1} else {lo};
+
+            reflect_rows(h, &p, k, first..=hi);
+            reflect_cols(h, &p, lo..=hi.min(k + 3), k);
+        }
+
+        x = h[(k + 1, k)];
+        y = h[(k + 2, k)];
+
+        if k + 3 <= hi {
+            z = h[(k + 3, k)];
+        }
+    }
+
+    if let Some(p) = reflector(&[x, y]) {
+        reflect_rows(h, &p, hi - 1, hi - 2..=hi);
+        reflect_cols(h, &p, lo..=hi, hi - 1);
+    }
+
+    //the reflectors leave rounding noise below the subdiagonal
+    for i in lo + 2..=hi {
+        for j in lo..i - 1 {
+            h[(i, j)] = 0.0;
+        }
+    }
+}
+
+/// Eigenvalues of an upper Hessenberg matrix by the implicit double shift QR algorithm
+/// (Golub and Van Loan, Matrix Computations, 7.5). The active window lo..=hi shrinks from the bottom
+/// as subdiagonal entries become negligible. Fails with NoConvergence if a window takes too many steps
+fn hessenberg_qr(mut h: Matrix<f64>) -> Result<Vec<Imaginary>, MathError> {
+    let n = h.rows();
+    let max_steps = 30 * n.max(10);
+
+    let norm = h.data().iter().map(|v| v.abs()).fold(0.0, f64::max);
+    let mut res = Vec::with_capacity(n);
+    let mut end = n;
+    let mut steps = 0;
+
+    while end > 0 {
+        let hi = end - 1;
+
+        //the window starts below the last negligible subdiagonal entry
+        let mut lo = hi;
+
+        while lo > 0 {
+            let mut scale = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+
+            if scale == 0.0 {
+                scale = norm;
+            }
+
+            if h[(lo, lo - 1)].abs() <= f64::EPSILON * scale {
+                h[(lo, lo - 1)] = 0.0;
+                break;
+            }
+
+            lo -= 1;
+        }
+
+        if lo == hi {
+            res.push(Imaginary::new(h[(hi, hi)], 0.0));
+            end -= 1;
+            steps = 0;
+            continue;
+        }
+
+        if lo + 1 == hi {
+            res.extend(block_eigenvalues(&h, lo));
+            end -= 2;
+            steps = 0;
+            continue;
+        }
+
+        if steps == max_steps {
+            return Err(MathError::NoConvergence);
+        }
+
+        steps += 1;
+
+        //the eigenvalues of the trailing 2x2 block are the shifts, every tenth step an exceptional shift
+        //breaks cycles, its constants are the ones of LAPACK's dlahqr
+        let (trace, det) = if steps % 10 == 0 {
+            let s = h[(hi, hi - 1)].abs() + h[(hi - 1, hi - 2)].abs();
+            let diag = h[(hi, hi)] + 0.75 * s;
+
+            (2.0 * diag, diag * diag + 0.4375 * s * s)
+        } else {
+            let (a, b, c, d) = (h[(hi - 1, hi - 1)], h[(hi - 1, hi)], h[(hi, hi - 1)], h[(hi, hi)]);
+
+            (a + d, a * d - b * c)
+        };
+
+        francis_step(&mut h, lo, hi, trace, det);
+    }
+
+    Ok(res)
+}
+
+impl Matrix<f64> {
+    /// Eigenvalues with multiplicities by Hessenberg reduction and shifted QR iteration, in no particular order.
+    /// Panics if the matrix is not square, fails with NoConvergence if the iteration does not converge
+    pub fn eigenvalues(&self) -> Result<Vec<Imaginary>, MathError> {
+        if !self.is_square() {
+            panic!("Eigenvalues of a {}x{} Matrix", self.rows(), self.cols());
+        }
+
+        let mut a = self.clone();
+
+        hessenberg(&mut a);
+
+        hessenberg_qr(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mtrx::Matrix;
+    use fraction::fract::Fraction;
+    use polynomial::{any_pnm::Polynomial, polynom};
+
+    /// The eigenvalues sorted by the real and then the imaginary part
+    fn spectrum(m: &Matrix<f64>) -> Vec<(f64, f64)> {
+        let mut res: Vec<(f64, f64)> = m.eigenvalues().unwrap().iter().map(|v| (v.real(), v.imaginary())).collect();
+
+        res.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        res
+    }
+
+    fn assert_spectrum(m: &Matrix<f64>, expected: &[(f64, f64)]) {
+        let res = spectrum(m);
+
+        assert_eq!(res.len(), expected.len());
+
+        for (a, b) in res.iter().zip(expected) {
+            assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9, "{:?} != {:?}", res, expected);
+        }
+    }
+
+    #[test]
+    fn charpoly_of_integer_matrix() {
+        let m = matrix![1i64, 2, 3; 4, 5, 6; 7, 8, 10];
+
+        //trace 16, the principal 2x2 minors add up to -12, determinant -3
+        assert_eq!(m.charpoly_ratios(), vec![1, -16, -12, 3]);
+        assert_eq!(matrix![2.0, 1.0; 1.0, 2.0].charpoly(), polynom![1.0, -4.0, 3.0]);
+    }
+
+    #[test]
+    fn cayley_hamilton() {
+        let f = |n: i64| Fraction::new(n, 1);
+        let m = matrix![f(2), f(-1), f(0); f(1), f(3), f(4); f(0), f(5), f(-2)];
+
+        assert_eq!(m.eval_polynomial(&m.charpoly()), Matrix::zero(3, 3));
+    }
+
+    #[test]
+    fn minimal_polynomial() {
+        let f = |n: i64| Fraction::new(n, 1);
+
+        assert_eq!(Matrix::<Fraction<i64>>::identity(3).minimal_polynomial(), polynom![f(1), f(-1)]);
+
+        //a Jordan block of 2 and a separate 2
+        let m = matrix![f(2), f(1), f(0); f(0), f(2), f(0); f(0), f(0), f(2)];
+        assert_eq!(m.minimal_polynomial(), polynom![f(1), f(-4), f(4)]);
+        assert_eq!(m.charpoly(), polynom![f(1), f(-6), f(12), f(-8)]);
+
+        let empty = Matrix::<Fraction<i64>>::new(0, 0, vec![]);
+        assert_eq!(empty.minimal_polynomial(), polynom![f(1)]);
+        assert_eq!(empty.charpoly(), polynom![f(1)]);
+    }
+
+    #[test]
+    fn companion_has_the_polynomial_as_charpoly() {
+        let p = polynom![2.0, -6.0, 4.0, 8.0];
+
+        assert_eq!(Matrix::companion(&p).charpoly(), polynom![1.0, -3.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn eigenvalues_of_rotation() {
+        assert_spectrum(&matrix![0.0, -1.0; 1.0, 0.0], &[(0.0, -1.0), (0.0, 1.0)]);
+
+        let (sin, cos) = 0.3f64.sin_cos();
+        let rotation = matrix![cos, -sin, 0.0; sin, cos, 0.0; 0.0, 0.0, 1.0];
+
+        assert_spectrum(&rotation, &[(cos, -sin), (cos, sin), (1.0, 0.0)]);
+    }
+
+    #[test]
+    fn eigenvalues_of_symmetric() {
+        let m = matrix![2.0, 1.0, 0.0; 1.0, 2.0, 1.0; 0.0, 1.0, 2.0];
+        let r = 2f64.sqrt();
+
+        assert_spectrum(&m, &[(2.0 - r, 0.0), (2.0, 0.0), (2.0 + r, 0.0)]);
+
+        //the second difference matrix has the eigenvalues 2 - 2cos(k pi / (n + 1))
+        let n = 8;
+        let m = Matrix::from_fn(n, n, |i, j| if i == j {2.0} else if i.abs_diff(j) == 1 {-1.0} else {0.0});
+        let expected: Vec<(f64, f64)> = (1..=n).map(|k| (2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n + 1) as f64).cos(), 0.0)).collect();
+
+        assert_spectrum(&m, &expected);
+    }
+
+    #[test]
+    fn eigenvalues_of_companion() {
+        //(x - 1)(x - 2)(x - 3)(x^2 + 1)
+        let p = polynom![1.0, -6.0, 12.0, -12.0, 11.0, -6.0];
+
+        assert_spectrum(&Matrix::companion(&p), &[(0.0, -1.0), (0.0, 1.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+    }
+
+    #[test]
+    fn eigenvalues_of_small_matrices() {
+        assert_spectrum(&Matrix::zero(0, 0), &[]);
+        assert_spectrum(&matrix![5.0], &[(5.0, 0.0)]);
+        assert_spectrum(&Matrix::zero(3, 3), &[(0.0, 0.0), (0.0, 0.0), (0.0, 0.0)]);
+
+        //already triangular
+        assert_spectrum(&matrix![1.0, 2.0, 3.0; 0.0, 4.0, 5.0; 0.0, 0.0, 6.0], &[(1.0, 0.0), (4.0, 0.0), (6.0, 0.0)]);
+    }
+
+    #[test]
+    fn eigenvalues_agree_with_charpoly() {
+        let m = matrix![4.0, -2.0, 1.0, 3.0; 1.0, 0.0, -5.0, 2.0; 2.0, 7.0, 1.0, -1.0; 0.5, 3.0, 2.0, 6.0];
+        let p: Polynomial<f64> = m.charpoly();
+
+        for v in m.eigenvalues().unwrap() {
+            //p at a complex point by Horner's scheme
+            let val = p.get().iter().rev().fold((0.0, 0.0), |(re, im), r| (re * v.real() - im * v.imaginary() + r, re * v.imaginary() + im * v.real()));
+
+            assert!(val.0.hypot(val.1) < 1e-8 * (1.0 + v.abs()).powi(4), "p({}) = {:?}", v, val);
+        }
+    }
+}
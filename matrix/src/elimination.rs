@@ -0,0 +1,236 @@
+//! Exact elimination: fraction-free determinants over integral domains,
+//! reduced row echelon form, rank, inverse and null space over fields
+
+use crate::mtrx::Matrix;
+use tech::{IntegralDomain, UnRing, Field, MathError};
+use std::ops::Div;
+
+impl<T> Matrix<T>
+where T: IntegralDomain + UnRing + Div<Output = T> + Clone {
+    /// Bareiss elimination: every division is exact, so integer and polynomial entries work
+    /// and the intermediate entries stay minors of the matrix. Panics if the matrix is not square
+    pub fn determinant(&self) -> T {
+        if !self.is_square() {
+            panic!("Determinant of a {}x{} Matrix", self.rows(), self.cols());
+        }
+
+        let n = self.rows();
+
+        if n == 0 {
+            return T::one();
+        }
+
+        let mut m = self.clone();
+        let mut negative = false;
+        let mut prev = T::one();
+
+        for k in 0..n - 1 {
+            if m[(k, k)].is_zero() {
+                match (k + 1..n).find(|&i| !m[(i, k)].is_zero()) {
+                    Some(i) => {
+                        m.swap_rows(k, i);
+                        negative = !negative;
+                    }
+                    None => return T::zero(),
+                }
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let val = m[(i, j)].clone() * m[(k, k)].clone() - m[(i, k)].clone() * m[(k, j)].clone();
+
+                    m[(i, j)] = val / prev.clone();
+                }
+            }
+
+            prev = m[(k, k)].clone();
+        }
+
+        let det = m[(n - 1, n - 1)].clone();
+
+        if negative {
+            T::zero() - det
+        } else {
+            det
+        }
+    }
+}
+
+impl<T: Field + Clone> Matrix<T> {
+    /// Gauss-Jordan elimination that takes the pivot of column c from the row returned by
+    /// `pivot(m, r, c)` (a row from r down), None means the column has no pivot
+    pub(crate) fn eliminate(&self, mut pivot: impl FnMut(&Matrix<T>, usize, usize) -> Option<usize>) -> (Matrix<T>, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        let mut r = 0;
+
+        for c in 0..m.cols() {
+            if r == m.rows() {
+                break;
+            }
+
+            let Some(p) = pivot(&m, r, c) else {
+                continue;
+            };
+
+            m.swap_rows(r, p);
+
+            let inv = T::one() / m[(r, c)].clone();
+
+            for j in c..m.cols() {
+                m[(r, j)] *= inv.clone();
+            }
+
+            for i in (0..m.rows()).filter(|&i| i != r) {
+                let factor = m[(i, c)].clone();
+
+                if factor.is_zero() {
+                    continue;
+                }
+
+                for j in c..m.cols() {
+                    let val = factor.clone() * m[(r, j)].clone();
+
+                    m[(i, j)] -= val;
+                }
+            }
+
+            pivots.push(c);
+            r += 1;
+        }
+
+        (m, pivots)
+    }
+
+    /// Reduced row echelon form and the columns of its pivots
+    pub fn rref_with_pivots(&self) -> (Matrix<T>, Vec<usize>) {
+        self.eliminate(|m, r, c| (r..m.rows()).find(|&i| !m[(i, c)].is_zero()))
+    }
+
+    pub fn rref(&self) -> Matrix<T> {
+        self.rref_with_pivots().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref_with_pivots().1.len()
+    }
+
+    /// Fails with NonInvertible for singular and non-square matrices
+    pub fn inverse(&self) -> Result<Matrix<T>, MathError> {
+        if !self.is_square() {
+            return Err(MathError::NonInvertible);
+        }
+
+        let n = self.rows();
+
+        //[self | I] turns into [I | self^-1]
+        let augmented = Matrix::from_fn(n, 2 * n, |i, j| {
+            if j < n {
+                self[(i, j)].clone()
+            } else if j - n == i {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+
+        let (m, pivots) = augmented.rref_with_pivots();
+
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return Err(MathError::NonInvertible);
+        }
+
+        Ok(Matrix::from_fn(n, n, |i, j| m[(i, j + n)].clone()))
+    }
+
+    /// A basis of the solutions of `self * x = 0`, one vector per free column
+    pub fn null_space(&self) -> Vec<Vec<T>> {
+        let (m, pivots) = self.rref_with_pivots();
+
+        null_basis(&m, &pivots, self.cols())
+    }
+}
+
+/// Null space basis read off a reduced row echelon form, only its first `cols` columns are used
+pub(crate) fn null_basis<T: Field + Clone>(m: &Matrix<T>, pivots: &[usize], cols: usize) -> Vec<Vec<T>> {
+    (0..cols).filter(|c| !pivots.contains(c)).map(|free| {
+        let mut v = vec![T::zero(); cols];
+        v[free] = T::one();
+
+        for (row, &p) in pivots.iter().enumerate() {
+            v[p] = T::zero() - m[(row, free)].clone();
+        }
+
+        v
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mtrx::Matrix;
+    use fraction::fract::Rational;
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::MathError;
+
+    fn r(num: i64, denom: i64) -> Rational<i64> {
+        Rational::new(num, denom)
+    }
+
+    #[test]
+    fn bareiss_determinant() {
+        assert_eq!(matrix![2i64, 3, 1; 4, 1, 5; 6, 2, 0].determinant(), 72);
+        assert_eq!(Matrix::<i64>::new(0, 0, vec![]).determinant(), 1);
+        assert_eq!(matrix![1i64, 2; 2, 4].determinant(), 0);
+        assert_eq!(matrix![0i64, 1; 0, 2].determinant(), 0);
+
+        //zero pivots at the start and after the first elimination step need a row swap
+        assert_eq!(matrix![0i64, 1, 2; 3, 4, 5; 6, 7, 9].determinant(), -3);
+        assert_eq!(matrix![1i64, 2, 3; 2, 4, 5; 3, 7, 1].determinant(), 1);
+
+        assert_eq!(matrix![r(1, 2), r(1, 3); r(1, 4), r(1, 5)].determinant(), r(1, 60));
+    }
+
+    #[test]
+    fn determinant_of_polynomials() {
+        let x: Polynomial<Rational<i64>> = polynom![r(1, 1), r(0, 1)];
+        let one = polynom![r(1, 1)];
+        let zero = polynom![r(0, 1)];
+
+        assert_eq!(matrix![x.clone(), one.clone(); one.clone(), x.clone()].determinant(), polynom![r(1, 1), r(0, 1), r(-1, 1)]);
+        assert_eq!(matrix![zero, x.clone(); x.clone(), one].determinant(), polynom![r(-1, 1), r(0, 1), r(0, 1)]);
+    }
+
+    #[test]
+    fn rref_rank_and_null_space() {
+        let a = matrix![r(1, 1), r(2, 1), r(1, 1); r(2, 1), r(4, 1), r(0, 1); r(3, 1), r(6, 1), r(1, 1)];
+        let (rref, pivots) = a.rref_with_pivots();
+
+        assert_eq!(rref, matrix![r(1, 1), r(2, 1), r(0, 1); r(0, 1), r(0, 1), r(1, 1); r(0, 1), r(0, 1), r(0, 1)]);
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(a.rank(), 2);
+
+        let null = a.null_space();
+        assert_eq!(null, vec![vec![r(-2, 1), r(1, 1), r(0, 1)]]);
+        assert_eq!(&a * &Matrix::new(3, 1, null[0].clone()), Matrix::zero(3, 1));
+
+        assert_eq!(Matrix::<Rational<i64>>::zero(2, 3).rank(), 0);
+        assert_eq!(Matrix::<Rational<i64>>::zero(2, 3).null_space().len(), 3);
+        assert_eq!(Matrix::<Rational<i64>>::identity(3).rank(), 3);
+        assert!(Matrix::<Rational<i64>>::identity(3).null_space().is_empty());
+    }
+
+    #[test]
+    fn inverse() {
+        let a = matrix![r(2, 1), r(0, 1), r(1, 1); r(1, 1), r(1, 1), r(0, 1); r(0, 1), r(1, 1), r(1, 1)];
+        let inv = a.inverse().unwrap();
+
+        assert_eq!(inv, matrix![r(1, 3), r(1, 3), r(-1, 3); r(-1, 3), r(2, 3), r(1, 3); r(1, 3), r(-2, 3), r(2, 3)]);
+        assert_eq!(&a * &inv, Matrix::identity(3));
+
+        //a zero in the top left corner needs a row swap
+        assert_eq!(matrix![r(0, 1), r(1, 1); r(1, 1), r(0, 1)].inverse(), Ok(matrix![r(0, 1), r(1, 1); r(1, 1), r(0, 1)]));
+
+        assert_eq!(matrix![r(1, 1), r(2, 1); r(2, 1), r(4, 1)].inverse(), Err(MathError::NonInvertible));
+        assert_eq!(Matrix::<Rational<i64>>::zero(2, 3).inverse(), Err(MathError::NonInvertible));
+    }
+}
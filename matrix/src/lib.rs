@@ -0,0 +1,504 @@
+//! # matrix
+//!
+//! Dense matrices over any ring from the tech crate
+
+#[macro_use]
+pub mod mtrx {
+    use tech::{Ring, UnRing, Meta, AssAdd, ComAdd, AssMul};
+    use std::{
+        ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Index, IndexMut},
+        fmt::Display,
+    };
+
+    /// Matrix with the entries stored row by row
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Matrix<T: Ring> {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    }
+
+    impl<T: Ring> Matrix<T> {
+        /// Panics if the length of data is not rows * cols
+        pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
+            if data.len() != rows * cols {
+                panic!("Creating a {}x{} Matrix from {} entries", rows, cols, data.len());
+            }
+
+            Matrix {rows, cols, data}
+        }
+
+        /// Panics if the rows have different lengths
+        pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+            let cols = rows.first().map_or(0, |r| r.len());
+
+            if rows.iter().any(|r| r.len() != cols) {
+                panic!("Creating a Matrix from rows of different lengths");
+            }
+
+            let n = rows.len();
+
+            Matrix {rows: n, cols, data: rows.into_iter().flatten().collect()}
+        }
+
+        pub fn rows(&self) -> usize {
+            self.rows
+        }
+
+        pub fn cols(&self) -> usize {
+            self.cols
+        }
+
+        pub fn is_square(&self) -> bool {
+            self.rows == self.cols
+        }
+
+        pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+            if i < self.rows && j < self.cols {
+                self.data.get(i * self.cols + j)
+            } else {
+                None
+            }
+        }
+
+        pub fn row(&self, i: usize) -> &[T] {
+            &self.data[i * self.cols..(i + 1) * self.cols]
+        }
+
+        /// The entries row by row
+        pub fn data(&self) -> &[T] {
+            &self.data
+        }
+
+        pub fn into_data(self) -> Vec<T> {
+            self.data
+        }
+
+        pub fn swap_rows(&mut self, a: usize, b: usize) {
+            if a == b {
+                return;
+            }
+
+            for j in 0..self.cols {
+                self.data.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+
+        pub fn swap_cols(&mut self, a: usize, b: usize) {
+            if a == b {
+                return;
+            }
+
+            for i in 0..self.rows {
+                self.data.swap(i * self.cols + a, i * self.cols + b);
+            }
+        }
+
+        /// Applies f to every entry
+        pub fn map<U: Ring>(&self, f: impl FnMut(&T) -> U) -> Matrix<U> {
+            Matrix {rows: self.rows, cols: self.cols, data: self.data.iter().map(f).collect()}
+        }
+    }
+
+    impl<T: Ring + Clone> Matrix<T> {
+        pub fn zero(rows: usize, cols: usize) -> Matrix<T> {
+            Matrix {rows, cols, data: vec![T::zero(); rows * cols]}
+        }
+
+        pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Matrix<T> {
+            let data = (0..rows * cols).map(|k| f(k / cols, k % cols)).collect();
+
+            Matrix {rows, cols, data}
+        }
+
+        pub fn col(&self, j: usize) -> Vec<T> {
+            (0..self.rows).map(|i| self[(i, j)].clone()).collect()
+        }
+
+        pub fn transpose(&self) -> Matrix<T> {
+            Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+        }
+
+        /// Every entry multiplied by val
+        pub fn scale(&self, val: &T) -> Matrix<T> {
+            self.map(|v| v.clone() * val.clone())
+        }
+
+        /// The entry (i, j) of self * rhs
+        fn row_by_col(&self, rhs: &Matrix<T>, i: usize, j: usize) -> T {
+            let mut res = T::zero();
+
+            for k in 0..self.cols {
+                res += self[(i, k)].clone() * rhs[(k, j)].clone();
+            }
+
+            res
+        }
+
+        fn check_same_size(&self, rhs: &Matrix<T>, op: &str) {
+            if self.rows != rhs.rows || self.cols != rhs.cols {
+                panic!("{} of a {}x{} and a {}x{} Matrix", op, self.rows, self.cols, rhs.rows, rhs.cols);
+            }
+        }
+    }
+
+    impl<T: UnRing + Clone> Matrix<T> {
+        pub fn identity(n: usize) -> Matrix<T> {
+            Matrix::from_fn(n, n, |i, j| if i == j {T::one()} else {T::zero()})
+        }
+    }
+
+    #[macro_export]
+    macro_rules! matrix {
+        ($($($val: expr),+);+ $(;)?) => {
+            Matrix::from_rows(vec![$(vec![$($val),+]),+])
+        };
+    }
+
+    impl<T: Ring> Index<(usize, usize)> for Matrix<T> {
+        type Output = T;
+
+        fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+            if i >= self.rows || j >= self.cols {
+                panic!("Index ({}, {}) out of a {}x{} Matrix", i, j, self.rows, self.cols);
+            }
+
+            &self.data[i * self.cols + j]
+        }
+    }
+
+    impl<T: Ring> IndexMut<(usize, usize)> for Matrix<T> {
+        fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+            if i >= self.rows || j >= self.cols {
+                panic!("Index ({}, {}) out of a {}x{} Matrix", i, j, self.rows, self.cols);
+            }
+
+            &mut self.data[i * self.cols + j]
+        }
+    }
+
+    impl<T: Ring + Clone> AddAssign for Matrix<T> {
+        fn add_assign(&mut self, rhs: Self) {
+            self.check_same_size(&rhs, "Adding");
+
+            for (l, r) in self.data.iter_mut().zip(rhs.data) {
+                *l += r;
+            }
+        }
+    }
+
+    impl<T: Ring + Clone> SubAssign for Matrix<T> {
+        fn sub_assign(&mut self, rhs: Self) {
+            self.check_same_size(&rhs, "Subtracting");
+
+            for (l, r) in self.data.iter_mut().zip(rhs.data) {
+                *l -= r;
+            }
+        }
+    }
+
+    impl<T: Ring + Clone> Add for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut res = self;
+            res += rhs;
+
+            res
+        }
+    }
+
+    impl<T: Ring + Clone> Sub for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            let mut res = self;
+            res -= rhs;
+
+            res
+        }
+    }
+
+    impl<T: Ring + Clone> Add for &Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            self.check_same_size(rhs, "Adding");
+
+            Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, j)].clone() + rhs[(i, j)].clone())
+        }
+    }
+
+    impl<T: Ring + Clone> Sub for &Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.check_same_size(rhs, "Subtracting");
+
+            Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, j)].clone() - rhs[(i, j)].clone())
+        }
+    }
+
+    impl<T: Ring + Clone> Mul for &Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            if self.cols != rhs.rows {
+                panic!("Multiplying a {}x{} and a {}x{} Matrix", self.rows, self.cols, rhs.rows, rhs.cols);
+            }
+
+            Matrix::from_fn(self.rows, rhs.cols, |i, j| self.row_by_col(rhs, i, j))
+        }
+    }
+
+    impl<T: Ring + Clone> Mul for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            &self * &rhs
+        }
+    }
+
+    impl<T: Ring + Clone> MulAssign for Matrix<T> {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = &*self * &rhs;
+        }
+    }
+
+    impl<T: Ring + Clone> Mul<T> for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            self.scale(&rhs)
+        }
+    }
+
+    impl<T: Ring + Clone> MulAssign<T> for Matrix<T> {
+        fn mul_assign(&mut self, rhs: T) {
+            for v in self.data.iter_mut() {
+                *v *= rhs.clone();
+            }
+        }
+    }
+
+    impl<T: Ring + Clone + Div<Output = T>> Div<T> for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn div(self, rhs: T) -> Self::Output {
+            self.map(|v| v.clone() / rhs.clone())
+        }
+    }
+
+    impl<T: Ring + Clone + DivAssign> DivAssign<T> for Matrix<T> {
+        fn div_assign(&mut self, rhs: T) {
+            for v in self.data.iter_mut() {
+                *v /= rhs.clone();
+            }
+        }
+    }
+
+    impl<T: Ring + Clone> Neg for Matrix<T> {
+        type Output = Matrix<T>;
+
+        fn neg(self) -> Self::Output {
+            self.map(|v| T::zero() - v.clone())
+        }
+    }
+
+    impl<T: Ring + Display> Display for Matrix<T> {
+        /// One row per line with the columns aligned to the right
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let cells: Vec<String> = self.data.iter().map(|v| v.to_string().trim().to_string()).collect();
+            let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+
+            for i in 0..self.rows {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+
+                let row: Vec<String> = cells[i * self.cols..(i + 1) * self.cols].iter()
+                    .map(|c| format!("{:>width$}", c, width = width))
+                    .collect();
+
+                write!(f, "[{}]", row.join(" "))?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// N x N matrix, the size is a part of the type so that square matrices form a ring
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Square<T: Ring, const N: usize> {
+        matrix: Matrix<T>,
+    }
+
+    impl<T: Ring, const N: usize> Square<T, N> {
+        /// Panics if the matrix is not N x N
+        pub fn new(matrix: Matrix<T>) -> Square<T, N> {
+            if matrix.rows != N || matrix.cols != N {
+                panic!("Creating a Square<{}> from a {}x{} Matrix", N, matrix.rows, matrix.cols);
+            }
+
+            Square {matrix}
+        }
+
+        pub fn matrix(&self) -> &Matrix<T> {
+            &self.matrix
+        }
+
+        pub fn into_matrix(self) -> Matrix<T> {
+            self.matrix
+        }
+    }
+
+    impl<T: Ring, const N: usize> From<Square<T, N>> for Matrix<T> {
+        fn from(val: Square<T, N>) -> Self {
+            val.matrix
+        }
+    }
+
+    impl<T: Ring, const N: usize> Index<(usize, usize)> for Square<T, N> {
+        type Output = T;
+
+        fn index(&self, index: (usize, usize)) -> &Self::Output {
+            &self.matrix[index]
+        }
+    }
+
+    impl<T: Ring, const N: usize> IndexMut<(usize, usize)> for Square<T, N> {
+        fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+            &mut self.matrix[index]
+        }
+    }
+
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl<T: Ring + Clone, const N: usize> $op for Square<T, N> {
+                    type Output = Square<T, N>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        Square {matrix: self.matrix.$method(rhs.matrix)}
+                    }
+                }
+
+                impl<T: Ring + Clone, const N: usize> $op for &Square<T, N> {
+                    type Output = Square<T, N>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        Square {matrix: (&self.matrix).$method(&rhs.matrix)}
+                    }
+                }
+
+                impl<T: Ring + Clone, const N: usize> $op_assign for Square<T, N> {
+                    fn $method_assign(&mut self, rhs: Self) {
+                        self.matrix.$method_assign(rhs.matrix);
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+    impl<T: Ring + Clone, const N: usize> Neg for Square<T, N> {
+        type Output = Square<T, N>;
+
+        fn neg(self) -> Self::Output {
+            Square {matrix: -self.matrix}
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Display for Square<T, N>
+    where T: Display {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.matrix.fmt(f)
+        }
+    }
+
+    //multiplying matrices is associative but not commutative, so Square is not an IntegralDomain
+    impl<T: Ring + Clone, const N: usize> AssAdd for Square<T, N> {}
+    impl<T: Ring + Clone, const N: usize> ComAdd for Square<T, N> {}
+    impl<T: Ring + Clone + AssMul, const N: usize> AssMul for Square<T, N> {}
+
+    impl<T: Ring + Clone, const N: usize> Ring for Square<T, N> {
+        fn zero() -> Self {
+            Square {matrix: Matrix::zero(N, N)}
+        }
+    }
+
+    impl<T: UnRing + Clone, const N: usize> UnRing for Square<T, N> {
+        fn one() -> Self {
+            Square {matrix: Matrix::identity(N)}
+        }
+    }
+
+    impl<T: UnRing + Meta + Clone, const N: usize> Meta for Square<T, N> {
+        fn non_zero() -> Self {
+            Square::one()
+        }
+
+        fn name() -> String {
+            format!("Square<{}, {}>", T::name(), N)
+        }
+    }
+}
+
+pub mod elimination;
+pub mod solve;
+pub mod eigen;
+pub mod normal_form;
+
+#[cfg(test)]
+mod tests {
+    use crate::mtrx::{Matrix, Square};
+    use imaginary::imgn::Imaginary;
+    use fraction::fract::Fraction;
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::{UnRing, Meta};
+
+    #[test]
+    fn f64_entries() {
+        let a = matrix![1.0, 2.0; 3.0, 4.0];
+
+        assert_eq!(&a * &Matrix::identity(2), a);
+        assert_eq!(a.transpose(), matrix![1.0, 3.0; 2.0, 4.0]);
+        assert_eq!(&a * &a, matrix![7.0, 10.0; 15.0, 22.0]);
+        assert_eq!(a.determinant(), -2.0);
+        assert_eq!(a.to_string(), "[1 2]\n[3 4]");
+    }
+
+    #[test]
+    fn imaginary_entries() {
+        let i = Imaginary::new(0.0, 1.0);
+        let a = Square::<Imaginary, 2>::new(matrix![i, Imaginary::new(1.0, 0.0); Imaginary::new(0.0, 0.0), i]);
+
+        //(iI + N)^2 = -I + 2iN for the nilpotent N
+        let expected = matrix![Imaginary::new(-1.0, 0.0), Imaginary::new(0.0, 2.0); Imaginary::new(0.0, 0.0), Imaginary::new(-1.0, 0.0)];
+
+        assert_eq!((&a * &a).into_matrix(), expected);
+        assert_eq!(Square::<Imaginary, 2>::name(), "Square<Imaginary, 2>");
+    }
+
+    #[test]
+    fn fraction_entries() {
+        let f = |n: i64, d: i64| Fraction::new(n, d);
+        let a = matrix![f(1, 1), f(2, 1); f(3, 1), f(4, 1)];
+
+        let inv = a.inverse().unwrap();
+
+        assert_eq!(inv, matrix![f(-2, 1), f(1, 1); f(3, 2), f(-1, 2)]);
+        assert_eq!(&a * &inv, Matrix::identity(2));
+    }
+
+    #[test]
+    fn polynomial_entries() {
+        //x I - A for A = [[2, 1], [1, 2]], its determinant is the characteristic polynomial
+        let x = polynom![1.0, 0.0];
+        let a = matrix![&x - &polynom![2.0], polynom![-1.0]; polynom![-1.0], &x - &polynom![2.0]];
+
+        assert_eq!(a.determinant(), polynom![1.0, -4.0, 3.0]);
+        assert_eq!(Square::<Polynomial<f64>, 2>::one().into_matrix(), Matrix::identity(2));
+    }
+}
@@ -0,0 +1,319 @@
+//! Hermite and Smith normal forms over Euclidean domains with the unimodular transforms
+
+use crate::mtrx::Matrix;
+use tech::{EuclideanDomain, Ring};
+
+impl<T: Ring + Clone> Matrix<T> {
+    /// Row `target` minus factor times row `source`
+    fn sub_row(&mut self, target: usize, source: usize, factor: &T) {
+        for j in 0..self.cols() {
+            let val = factor.clone() * self[(source, j)].clone();
+
+            self[(target, j)] -= val;
+        }
+    }
+
+    /// Column `target` minus column `source` times factor
+    fn sub_col(&mut self, target: usize, source: usize, factor: &T) {
+        for i in 0..self.rows() {
+            let val = self[(i, source)].clone() * factor.clone();
+
+            self[(i, target)] -= val;
+        }
+    }
+
+    fn scale_row(&mut self, i: usize, factor: &T) {
+        for j in 0..self.cols() {
+            self[(i, j)] *= factor.clone();
+        }
+    }
+}
+
+/// The row from `from` down with the non-zero entry of the smallest norm in column c
+fn smallest_in_col<T: EuclideanDomain + Clone>(m: &Matrix<T>, from: usize, c: usize) -> Option<usize> {
+    (from..m.rows()).filter(|&i| !m[(i, c)].is_zero()).min_by_key(|&i| m[(i, c)].norm())
+}
+
+impl<T: EuclideanDomain + Clone> Matrix<T> {
+    /// Row style Hermite normal form H and a unimodular U with `U * self = H`.
+    /// H is in echelon form with canonical pivots (positive integers, monic polynomials)
+    /// and the entries above each pivot reduced modulo the pivot
+    pub fn hermite_form(&self) -> (Matrix<T>, Matrix<T>) {
+        let mut h = self.clone();
+        let mut u = Matrix::identity(self.rows());
+        let mut r = 0;
+
+        for c in 0..self.cols() {
+            if r == self.rows() {
+                break;
+            }
+
+            //Euclid's algorithm on the column until a single non-zero entry is left
+            while let Some(p) = smallest_in_col(&h, r, c) {
+                h.swap_rows(r, p);
+                u.swap_rows(r, p);
+
+                let mut done = true;
+
+                for i in r + 1..self.rows() {
+                    let q = h[(i, c)].euclid_div(&h[(r, c)]);
+
+                    h.sub_row(i, r, &q);
+                    u.sub_row(i, r, &q);
+
+                    done &= h[(i, c)].is_zero();
+                }
+
+                if done {
+                    break;
+                }
+            }
+
+            if h[(r, c)].is_zero() {
+                continue;
+            }
+
+            let unit = h[(r, c)].normalizer();
+
+            h.scale_row(r, &unit);
+            u.scale_row(r, &unit);
+
+            for i in 0..r {
+                let q = h[(i, c)].euclid_div(&h[(r, c)]);
+
+                h.sub_row(i, r, &q);
+                u.sub_row(i, r, &q);
+            }
+
+            r += 1;
+        }
+
+        (h, u)
+    }
+
+    /// Smith normal form S and unimodular U and V with `U * self * V = S`.
+    /// S is diagonal, its diagonal entries are canonical and each one divides the next
+    pub fn smith_form(&self) -> (Matrix<T>, Matrix<T>, Matrix<T>) {
+        let (rows, cols) = (self.rows(), self.cols());
+
+        let mut s = self.clone();
+        let mut u = Matrix::identity(rows);
+        let mut v = Matrix::identity(cols);
+
+        for t in 0..rows.min(cols) {
+            //every restart strictly decreases the norm of the pivot
+            loop {
+                let pivot = (t..rows).flat_map(|i| (t..cols).map(move |j| (i, j)))
+                    .filter(|&(i, j)| !s[(i, j)].is_zero())
+                    .min_by_key(|&(i, j)| s[(i, j)].norm());
+
+                let Some((i, j)) = pivot else {
+                    return (s, u, v);
+                };
+
+                s.swap_rows(t, i);
+                u.swap_rows(t, i);
+                s.swap_cols(t, j);
+                v.swap_cols(t, j);
+
+                let mut done = true;
+
+                for i in t + 1..rows {
+                    let q = s[(i, t)].euclid_div(&s[(t, t)]);
+
+                    s.sub_row(i, t, &q);
+                    u.sub_row(i, t, &q);
+
+                    done &= s[(i, t)].is_zero();
+                }
+
+                for j in t + 1..cols {
+                    let q = s[(t, j)].euclid_div(&s[(t, t)]);
+
+                    s.sub_col(j, t, &q);
+                    v.sub_col(j, t, &q);
+
+                    done &= s[(t, j)].is_zero();
+                }
+
+                if !done {
+                    continue;
+                }
+
+                //a row with an entry the pivot does not divide is added to the pivot row
+                let rest = (t + 1..rows).find(|&i| {
+                    (t + 1..cols).any(|j| !s[(i, j)].euclid_rem(&s[(t, t)]).is_zero())
+                });
+
+                match rest {
+                    Some(i) => {
+                        let minus_one = T::zero() - T::one();
+
+                        s.sub_row(t, i, &minus_one);
+                        u.sub_row(t, i, &minus_one);
+                    }
+                    None => break,
+                }
+            }
+
+            let unit = s[(t, t)].normalizer();
+
+            s.scale_row(t, &unit);
+            u.scale_row(t, &unit);
+        }
+
+        (s, u, v)
+    }
+
+    /// The non-zero diagonal entries of the Smith normal form, e.g. the torsion
+    /// of the abelian group presented by an integer matrix
+    pub fn invariant_factors(&self) -> Vec<T> {
+        let (s, _, _) = self.smith_form();
+
+        (0..s.rows().min(s.cols())).map(|i| s[(i, i)].clone()).filter(|v| !v.is_zero()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mtrx::Matrix;
+    use fraction::fract::Rational;
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::{Ring, EuclidDiv};
+
+    type Poly = Polynomial<Rational<i64>>;
+
+    fn p(ratios: &[i64]) -> Poly {
+        Polynomial::new(ratios.iter().map(|v| Rational::new(*v, 1)).collect())
+    }
+
+    /// The first non-zero column of every row
+    fn pivots<T: Ring>(h: &Matrix<T>) -> Vec<Option<usize>> {
+        (0..h.rows()).map(|i| (0..h.cols()).find(|&j| !h[(i, j)].is_zero())).collect()
+    }
+
+    fn assert_echelon<T: Ring>(h: &Matrix<T>) {
+        let pivots = pivots(h);
+
+        for w in pivots.windows(2) {
+            match (w[0], w[1]) {
+                (Some(a), Some(b)) => assert!(a < b, "pivots {:?} are not increasing", pivots),
+                (None, Some(_)) => panic!("zero row above a non-zero one"),
+                _ => {}
+            }
+        }
+    }
+
+    fn assert_diagonal_chain<T: Ring + EuclidDiv>(s: &Matrix<T>) {
+        for i in 0..s.rows() {
+            for j in (0..s.cols()).filter(|&j| j != i) {
+                assert!(s[(i, j)].is_zero(), "S is not diagonal");
+            }
+        }
+
+        let diag: Vec<&T> = (0..s.rows().min(s.cols())).map(|i| &s[(i, i)]).collect();
+
+        for w in diag.windows(2) {
+            assert!(w[0].is_zero() && w[1].is_zero() || w[1].euclid_rem(w[0]).is_zero(), "the diagonal is no divisor chain");
+        }
+    }
+
+    fn check_int(m: &Matrix<i64>) {
+        let (h, u) = m.hermite_form();
+
+        assert_eq!(&u * m, h);
+        assert_eq!(u.determinant().abs(), 1);
+        assert_echelon(&h);
+
+        for (r, c) in pivots(&h).into_iter().enumerate().filter_map(|(r, c)| Some((r, c?))) {
+            assert!(h[(r, c)] > 0);
+
+            for i in 0..r {
+                assert!((0..h[(r, c)]).contains(&h[(i, c)]), "{} above the pivot {}", h[(i, c)], h[(r, c)]);
+            }
+        }
+
+        let (s, u, v) = m.smith_form();
+
+        assert_eq!(&(&u * m) * &v, s);
+        assert_eq!(u.determinant().abs(), 1);
+        assert_eq!(v.determinant().abs(), 1);
+        assert_diagonal_chain(&s);
+        assert!((0..s.rows().min(s.cols())).all(|i| s[(i, i)] >= 0));
+    }
+
+    fn check_poly(m: &Matrix<Poly>) {
+        let is_unit = |d: Poly| d.deg() == 0 && !d.lead().is_zero();
+
+        let (h, u) = m.hermite_form();
+
+        assert_eq!(&u * m, h);
+        assert!(is_unit(u.determinant()));
+        assert_echelon(&h);
+
+        for (r, c) in pivots(&h).into_iter().enumerate().filter_map(|(r, c)| Some((r, c?))) {
+            assert_eq!(*h[(r, c)].lead(), Rational::new(1, 1));
+
+            for i in 0..r {
+                assert!(h[(i, c)].is_zero() || h[(i, c)].deg() < h[(r, c)].deg());
+            }
+        }
+
+        let (s, u, v) = m.smith_form();
+
+        assert_eq!(&(&u * m) * &v, s);
+        assert!(is_unit(u.determinant()));
+        assert!(is_unit(v.determinant()));
+        assert_diagonal_chain(&s);
+    }
+
+    #[test]
+    fn integer_hermite_form() {
+        let m = matrix![2i64, 3, 6, 2; 5, 6, 1, 6; 8, 3, 1, 1];
+
+        assert_eq!(m.hermite_form().0, matrix![1, 0, 50, -11; 0, 3, 28, -2; 0, 0, 61, -13]);
+        check_int(&m);
+
+        let m = matrix![3i64, 3, 1, 4; 0, 1, 0, 0; 0, 0, 19, 16; 0, 0, 0, 3];
+
+        assert_eq!(m.hermite_form().0, matrix![3, 0, 1, 1; 0, 1, 0, 0; 0, 0, 19, 1; 0, 0, 0, 3]);
+        check_int(&m);
+    }
+
+    #[test]
+    fn integer_smith_form() {
+        let m = matrix![2i64, 4, 4; -6, 6, 12; 10, -4, -16];
+
+        assert_eq!(m.invariant_factors(), vec![2, 6, 12]);
+        check_int(&m);
+
+        //not square: the gcd of the entries and of the 2x2 minors give 2 and 2
+        let m = matrix![6i64, 4, 2; 4, 8, 6];
+
+        assert_eq!(m.invariant_factors(), vec![2, 2]);
+        check_int(&m);
+        check_int(&m.transpose());
+
+        let m = matrix![1i64, 2; 2, 4; 3, 6];
+
+        assert_eq!(m.invariant_factors(), vec![1]);
+        check_int(&m);
+    }
+
+    #[test]
+    fn polynomial_normal_forms() {
+        //xI - A for a Jordan block of 1 and for 2I
+        let m = matrix![p(&[1, -1]), p(&[-1]); p(&[0]), p(&[1, -1])];
+
+        assert_eq!(m.invariant_factors(), vec![p(&[1]), p(&[1, -2, 1])]);
+        check_poly(&m);
+
+        let m = matrix![p(&[1, -2]), p(&[0]); p(&[0]), p(&[1, -2])];
+
+        assert_eq!(m.invariant_factors(), vec![p(&[1, -2]), p(&[1, -2])]);
+        check_poly(&m);
+
+        let m = matrix![p(&[2, 0]), p(&[1]), polynom![Rational::new(1, 2), Rational::new(0, 1)]; p(&[1, 0, 0]), p(&[1, 1]), p(&[3])];
+        check_poly(&m);
+    }
+}
@@ -0,0 +1,343 @@
+//! Linear systems `A x = b`: exact elimination for any field, partial pivoting and LU
+//! for fields with a magnitude, least squares for f64
+
+use crate::{mtrx::Matrix, elimination::null_basis};
+use tech::{Field, Magnitude, MathError, Tolerance};
+
+/// Solutions of a linear system
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<T> {
+    Unique(Vec<T>),
+    /// `particular + t1 basis[0] + t2 basis[1] + ...` is a solution for any t1, t2, ...
+    General {
+        particular: Vec<T>,
+        basis: Vec<Vec<T>>,
+    },
+    /// The rank of the augmented matrix `[A | b]` is greater than the rank of A
+    Inconsistent {
+        rank: usize,
+        augmented_rank: usize,
+    },
+}
+
+impl<T: Field + Clone> Matrix<T> {
+    /// `[self | b]`, panics if b does not have an entry for every row
+    fn augment(&self, b: &[T]) -> Matrix<T> {
+        if b.len() != self.rows() {
+            panic!("Solving a system with {} equations and {} right-hand sides", self.rows(), b.len());
+        }
+
+        Matrix::from_fn(self.rows(), self.cols() + 1, |i, j| {
+            if j < self.cols() {self[(i, j)].clone()} else {b[i].clone()}
+        })
+    }
+
+    /// Reads the solution off the reduced echelon form of `[self | b]`
+    fn solution(&self, m: &Matrix<T>, pivots: &[usize]) -> Solution<T> {
+        let n = self.cols();
+
+        if pivots.last() == Some(&n) {
+            return Solution::Inconsistent {rank: pivots.len() - 1, augmented_rank: pivots.len()};
+        }
+
+        let mut particular = vec![T::zero(); n];
+
+        for (row, &p) in pivots.iter().enumerate() {
+            particular[p] = m[(row, n)].clone();
+        }
+
+        let basis = null_basis(m, pivots, n);
+
+        if basis.is_empty() {
+            Solution::Unique(particular)
+        } else {
+            Solution::General {particular, basis}
+        }
+    }
+
+    /// Exact Gauss-Jordan elimination, meant for Fraction, Reducible and other exact fields.
+    /// Floating point systems go through `solve_pivoted` instead
+    pub fn solve(&self, b: &[T]) -> Solution<T> {
+        let (m, pivots) = self.augment(b).rref_with_pivots();
+
+        self.solution(&m, &pivots)
+    }
+}
+
+impl<T: Field + Magnitude + Clone> Matrix<T> {
+    /// The largest entry below which `tol` treats a pivot candidate as zero
+    fn threshold(m: &Matrix<T>, tol: Tolerance) -> f64 {
+        let scale = m.data().iter().map(|v| v.magnitude()).fold(0.0, f64::max);
+
+        tol.abs.max(tol.rel * scale)
+    }
+
+    /// Solves the system for floating point entries: a square system with a pivot in every column goes through `lu`,
+    /// the others through Gauss-Jordan elimination with partial pivoting, which also finds general solutions and inconsistency.
+    /// A column has no pivot if all its candidates are within `tol.abs` or `tol.rel` times the largest entry
+    pub fn solve_pivoted(&self, b: &[T], tol: Tolerance) -> Solution<T> {
+        let augmented = self.augment(b);
+
+        if let Ok(lu) = self.lu(tol) {
+            return Solution::Unique(lu.solve(b));
+        }
+
+        let threshold = Self::threshold(&augmented, tol);
+
+        let (m, pivots) = augmented.eliminate(|m, r, c| {
+            let best = (r..m.rows()).max_by(|&i, &j| m[(i, c)].magnitude().total_cmp(&m[(j, c)].magnitude()))?;
+
+            if m[(best, c)].magnitude() > threshold {Some(best)} else {None}
+        });
+
+        self.solution(&m, &pivots)
+    }
+
+    /// LU decomposition with partial pivoting, fails with NonInvertible for non-square matrices
+    /// and when a pivot is within `tol.abs` or `tol.rel` times the largest entry of self
+    pub fn lu(&self, tol: Tolerance) -> Result<Lu<T>, MathError> {
+        if !self.is_square() {
+            return Err(MathError::NonInvertible);
+        }
+
+        let n = self.rows();
+        let threshold = Self::threshold(self, tol);
+
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut negative = false;
+
+        for k in 0..n {
+            let p = (k..n).max_by(|&i, &j| lu[(i, k)].magnitude().total_cmp(&lu[(j, k)].magnitude())).unwrap_or(k);
+
+            if lu[(p, k)].magnitude() <= threshold {
+                return Err(MathError::NonInvertible);
+            }
+
+            if p != k {
+                lu.swap_rows(p, k);
+                perm.swap(p, k);
+                negative = !negative;
+            }
+
+            for i in k + 1..n {
+                let factor = lu[(i, k)].clone() / lu[(k, k)].clone();
+
+                for j in k + 1..n {
+                    let val = factor.clone() * lu[(k, j)].clone();
+
+                    lu[(i, j)] -= val;
+                }
+
+                lu[(i, k)] = factor;
+            }
+        }
+
+        Ok(Lu {lu, perm, negative})
+    }
+}
+
+/// `P A = L U` with a unit lower triangular L, both triangles are kept in one matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<T: Field> {
+    lu: Matrix<T>,
+    perm: Vec<usize>,
+    negative: bool,
+}
+
+impl<T: Field + Clone> Lu<T> {
+    /// Row i of `P A` is the row `permutation()[i]` of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.rows();
+
+        Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                T::one()
+            } else if j < i {
+                self.lu[(i, j)].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.lu.rows();
+
+        Matrix::from_fn(n, n, |i, j| if j >= i {self.lu[(i, j)].clone()} else {T::zero()})
+    }
+
+    pub fn determinant(&self) -> T {
+        let det = (0..self.lu.rows()).fold(T::one(), |acc, i| acc * self.lu[(i, i)].clone());
+
+        if self.negative {-det} else {det}
+    }
+
+    /// Panics if b does not have an entry for every row
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.lu.rows();
+
+        if b.len() != n {
+            panic!("Solving a system with {} equations and {} right-hand sides", n, b.len());
+        }
+
+        //L y = P b, then U x = y
+        let mut x: Vec<T> = self.perm.iter().map(|&i| b[i].clone()).collect();
+
+        for i in 0..n {
+            for j in 0..i {
+                let val = self.lu[(i, j)].clone() * x[j].clone();
+
+                x[i] -= val;
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let val = self.lu[(i, j)].clone() * x[j].clone();
+
+                x[i] -= val;
+            }
+
+            x[i] = x[i].clone() / self.lu[(i, i)].clone();
+        }
+
+        x
+    }
+}
+
+impl Matrix<f64> {
+    /// The x minimizing `|self * x - b|` by Householder QR.
+    /// Fails with NonInvertible if the columns are linearly dependent
+    pub fn least_squares(&self, b: &[f64]) -> Result<Vec<f64>, MathError> {
+        let (rows, cols) = (self.rows(), self.cols());
+
+        if b.len() != rows {
+            panic!("Solving a system with {} equations and {} right-hand sides", rows, b.len());
+        }
+
+        if rows < cols {
+            return Err(MathError::NonInvertible);
+        }
+
+        let mut r = self.clone();
+        let mut qtb = b.to_vec();
+
+        for k in 0..cols {
+            let norm = (k..rows).map(|i| r[(i, k)] * r[(i, k)]).sum::<f64>().sqrt();
+
+            if norm == 0.0 {
+                return Err(MathError::NonInvertible);
+            }
+
+            //the reflection maps the column onto alpha * e_k, the sign avoids cancellation
+            let alpha = if r[(k, k)] > 0.0 {-norm} else {norm};
+
+            let mut v: Vec<f64> = (k..rows).map(|i| r[(i, k)]).collect();
+            v[0] -= alpha;
+
+            let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+
+            for j in k..cols {
+                let dot: f64 = (k..rows).map(|i| v[i - k] * r[(i, j)]).sum();
+
+                for i in k..rows {
+                    r[(i, j)] -= 2.0 * dot / v_norm2 * v[i - k];
+                }
+            }
+
+            let dot: f64 = (k..rows).map(|i| v[i - k] * qtb[i]).sum();
+
+            for i in k..rows {
+                qtb[i] -= 2.0 * dot / v_norm2 * v[i - k];
+            }
+        }
+
+        let largest = (0..cols).map(|i| r[(i, i)].abs()).fold(0.0, f64::max);
+
+        if (0..cols).any(|i| r[(i, i)].abs() <= f64::EPSILON * rows as f64 * largest) {
+            return Err(MathError::NonInvertible);
+        }
+
+        let mut x = vec![0.0; cols];
+
+        for i in (0..cols).rev() {
+            let sum: f64 = (i + 1..cols).map(|j| r[(i, j)] * x[j]).sum();
+
+            x[i] = (qtb[i] - sum) / r[(i, i)];
+        }
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mtrx::Matrix, solve::Solution};
+    use fraction::fract::Fraction;
+    use tech::{MathError, Tolerance};
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12)
+    }
+
+    #[test]
+    fn lu_factors() {
+        let a = matrix![1.0, 2.0, 0.0; 3.0, 1.0, 1.0; 0.0, 4.0, 2.0];
+        let lu = a.lu(Tolerance::default()).unwrap();
+
+        let pa = Matrix::from_fn(3, 3, |i, j| a[(lu.permutation()[i], j)]);
+
+        assert!(close((&lu.l() * &lu.u()).data(), pa.data()));
+        assert!((lu.determinant() - -14.0).abs() < 1e-12);
+        assert!(close(&lu.solve(&[5.0, 6.0, 10.0]), &a.solve_pivoted(&[5.0, 6.0, 10.0], Tolerance::default()).unique()));
+    }
+
+    #[test]
+    fn lu_singular_up_to_rounding() {
+        //the second row is three times the first one up to rounding, the second pivot is about -5.6e-17 instead of zero
+        let a = matrix![0.3, 1.0 / 3.0; 0.3 * 3.0, 1.0];
+
+        assert_eq!(a.lu(Tolerance::default()), Err(MathError::NonInvertible));
+        assert_eq!(a.lu(Tolerance::absolute(0.0)).map(|_| ()), Ok(()));
+    }
+
+    #[test]
+    fn solve_pivoted_kinds() {
+        let tol = Tolerance::default();
+
+        let unique = matrix![2.0, 1.0; 1.0, 3.0].solve_pivoted(&[3.0, 5.0], tol);
+        assert!(close(&unique.unique(), &[0.8, 1.4]));
+
+        match matrix![1.0, 2.0; 2.0, 4.0].solve_pivoted(&[3.0, 6.0], tol) {
+            Solution::General {particular, basis} => {
+                assert!(close(&particular, &[3.0, 0.0]));
+                assert_eq!(basis.len(), 1);
+            }
+            other => panic!("expected a general solution, got {:?}", other),
+        }
+
+        assert_eq!(matrix![1.0, 2.0; 2.0, 4.0].solve_pivoted(&[3.0, 7.0], tol), Solution::Inconsistent {rank: 1, augmented_rank: 2});
+    }
+
+    #[test]
+    fn solve_exact() {
+        let f = |n: i64, d: i64| Fraction::new(n, d);
+        let a = matrix![f(1, 1), f(2, 1); f(3, 1), f(4, 1)];
+
+        assert_eq!(a.solve(&[f(5, 1), f(6, 1)]), Solution::Unique(vec![f(-4, 1), f(9, 2)]));
+    }
+
+    impl<T> Solution<T> {
+        fn unique(self) -> Vec<T> {
+            match self {
+                Solution::Unique(x) => x,
+                _ => panic!("expected a unique solution"),
+            }
+        }
+    }
+}
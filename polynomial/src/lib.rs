@@ -6,17 +6,17 @@
 pub mod any_pnm{
     use std::{
         vec,
-        ops :: {Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg, Div, Rem},
+        ops :: {Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg, Div, DivAssign, Rem},
         fmt::Display,
     };
-    use tech::{Field, Ring, AssAdd, ComAdd, AssMul, ComMul, UnRing, IntegralDomain, Meta, Gcd};
+    use tech::{Field, DivisionRing, Ring, AssAdd, ComAdd, AssMul, ComMul, UnRing, IntegralDomain, Meta, Gcd, EuclidDiv, EuclideanDomain, ApproxEq, Tolerance, MathError};
 
     #[derive(PartialEq, Debug, Clone)]
-    pub struct Polynomial <T: Field> {
+    pub struct Polynomial <T: DivisionRing> {
         ratios: Vec<T>,
     } 
 
-    impl<T> Polynomial<T> where T : Field {
+    impl<T> Polynomial<T> where T : DivisionRing {
         pub fn new(mut ratios: Vec<T>) -> Polynomial<T> {
             ratios.reverse();
             let mut res = Polynomial {ratios};
@@ -56,45 +56,56 @@ pub mod any_pnm{
             &self.ratios[..]
         }
 
-        //is not overflow safe
+        /// Same as eval, takes the polynomial and the value by value
         pub fn evaluate(self, val: T) -> T 
         where T: std::iter::Sum + Clone,
         for <'a> &'a T: Mul<&'a T, Output = T>{
-            self.ratios.into_iter().enumerate().map(
-                |v| {
-                    fn fast_pow<T>(n: &T, pow: usize) -> T 
-                    where T: Field + Clone,
-                    for <'a> &'a T: Mul<&'a T, Output = T>{
-                        let mut cur_n = n.clone();
-
-                        let mut cur_pow = 1;
-                        while cur_pow < pow {
-                            if cur_pow + 1 == pow {
-                                cur_n *= n.clone();
-                                cur_pow += 1;
-                            } else if cur_pow * 2 <= pow {
-                                cur_n = &cur_n * &cur_n;
-
-                                cur_pow *= 2;
-                            } else {
-                                cur_n = fast_pow::<T>(n, pow - cur_pow);
-
-                                cur_pow = pow;
-                            }
-                        }
+            self.eval(&val)
+        }
+    }
 
-                        cur_n
-                    }
-                    
-                    let(pow, cur_ratio) = v;
+    impl<T> Polynomial<T> where T: DivisionRing + Clone {
+        /// The value at x by Horner's scheme, does not consume the polynomial.
+        /// For ratios that do not commute the powers of x stand to the right of the ratios
+        pub fn eval(&self, x: &T) -> T {
+            self.eval_in(x)
+        }
 
-                    if cur_ratio != T::zero() {
-                        cur_ratio * fast_pow::<T>(&val, pow)
-                    } else {
-                        T::zero()
-                    }
-                }
-            ).sum()
+        /// The value at x from a ring that contains the ratios, e.g. at a dual number for the derivative.
+        /// Dual numbers have zero divisors, so they are no DivisionRing and can be neither the ratios of a Polynomial
+        /// nor the argument of eval
+        pub fn eval_in<U: Ring + From<T> + Clone>(&self, x: &U) -> U {
+            let mut res = U::zero();
+
+            for ratio in self.ratios.iter().rev() {
+                res = res * x.clone() + U::from(ratio.clone());
+            }
+
+            res
+        }
+
+        pub fn derivative(&self) -> Polynomial<T> {
+            let mut ratios = Vec::with_capacity(self.ratios.len());
+            let mut pow = T::zero();
+
+            for ratio in self.ratios.iter().skip(1) {
+                pow += T::one();
+                ratios.push(pow.clone() * ratio.clone());
+            }
+
+            if ratios.is_empty() {
+                ratios.push(T::zero());
+            }
+
+            let mut res = Polynomial {ratios};
+            res.rm_lead_zero();
+
+            res
+        }
+
+        /// The ratio of the highest power
+        pub fn lead(&self) -> &T {
+            &self.ratios[self.deg()]
         }
     }
 
@@ -116,7 +127,7 @@ pub mod any_pnm{
         };
     }
 
-    impl<T> Add for Polynomial<T> where T: Field + Clone{
+    impl<T> Add for Polynomial<T> where T: DivisionRing + Clone{
         type Output = Self;
 
         fn add(self, rhs: Self) -> Self::Output{
@@ -128,7 +139,7 @@ pub mod any_pnm{
         }
     }
 
-    impl<T> AddAssign for Polynomial<T> where T: Field + Clone{
+    impl<T> AddAssign for Polynomial<T> where T: DivisionRing + Clone{
         fn add_assign(&mut self, rhs: Self) {
             let s_deg = self.deg();
             let r_deg = rhs.deg();
@@ -147,7 +158,7 @@ pub mod any_pnm{
         }
     }
     
-    impl<T> Sub for Polynomial<T> where T: Field + Clone {
+    impl<T> Sub for Polynomial<T> where T: DivisionRing + Clone {
         type Output = Self;
 
         fn sub(self, rhs: Self) -> Self::Output {
@@ -159,7 +170,7 @@ pub mod any_pnm{
         }
     }
 
-    impl<T> SubAssign for Polynomial<T> where T: Field + Clone {
+    impl<T> SubAssign for Polynomial<T> where T: DivisionRing + Clone {
         fn sub_assign(&mut self, rhs: Self) {
             let deg1 = self.deg();
             let deg2 = rhs.deg();
@@ -178,7 +189,7 @@ pub mod any_pnm{
         }
     }
 
-    impl<T> Mul<T> for Polynomial<T> where T: Field + Clone,
+    impl<T> Mul<T> for Polynomial<T> where T: DivisionRing + Clone,
     for<'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Self;
 
@@ -191,14 +202,14 @@ pub mod any_pnm{
     }
 
     impl<T> MulAssign<T> for Polynomial<T> 
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for<'a> &'a T: Mul<&'a T, Output = T> {
         fn mul_assign(&mut self, rhs: T) {
             self.ratios = self.ratios.iter().map(|v| v * &rhs).collect();
         }
     }
 
-    impl<T> Neg for Polynomial<T> where T: Field + Clone,
+    impl<T> Neg for Polynomial<T> where T: DivisionRing + Clone,
     for<'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Self;
 
@@ -209,7 +220,7 @@ pub mod any_pnm{
 
     //I dont like that i have to be able to multiply T refs in order to multiply Polynomial::<T>
     impl<T> Mul for Polynomial<T> 
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Polynomial<T>;
 
@@ -222,7 +233,7 @@ pub mod any_pnm{
     }
 
     impl<T> MulAssign for Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn mul_assign(&mut self, rhs: Self) {
             let s_deg = self.deg();
@@ -244,7 +255,7 @@ pub mod any_pnm{
     }
     
     impl<T> Add for &Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Add<&'a T, Output = T>{
         type Output = Polynomial<T>;
 
@@ -274,7 +285,7 @@ pub mod any_pnm{
     }
 
     impl<T> Sub for &Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Sub<&'a T, Output = T> + Neg<Output = T> {
         type Output = Polynomial<T>;
 
@@ -304,7 +315,7 @@ pub mod any_pnm{
     }
 
     impl<T> Mul for &Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Polynomial<T>;
         
@@ -325,7 +336,7 @@ pub mod any_pnm{
     } 
 
     impl<T> Mul<&T> for &Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for<'a> &'a T: Mul<&'a T, Output = T> {
         type Output = Polynomial<T>;
 
@@ -335,7 +346,7 @@ pub mod any_pnm{
     }
 
     impl<T> Display for Polynomial<T> 
-    where T: Field,
+    where T: DivisionRing,
     for <'a> &'a T: Display {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             let mut ratios_it = self.ratios.iter().enumerate().rev(); 
@@ -393,46 +404,109 @@ pub mod any_pnm{
         }
     }
 
-    impl<T: Field + Clone> Polynomial<T>
+    impl<T: DivisionRing + Clone> Polynomial<T>
     where for <'a> &'a T: Mul<&'a T, Output = T> +  Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T>{
+        pub fn try_div_with_rem(&self, rhs: &Polynomial<T>) -> Result<(Polynomial<T>, Polynomial<T>), MathError> {
+            if rhs.is_zero() {
+                return Err(MathError::DivisionByZero);
+            }
+
+            Ok(self.div_with_rem(rhs))
+        }
+
+        /// The inverse exists only for non-zero constants
+        pub fn inverse(&self) -> Result<Polynomial<T>, MathError> {
+            if self.deg() != 0 || self.ratios[0].is_zero() {
+                return Err(MathError::NonInvertible);
+            }
+
+            Ok(polynom!(T::one() / self.ratios[0].clone()))
+        }
+
+        /// `self = q * rhs + r` with `deg r < deg rhs`, the ratios of q are found by the right division `/`.
+        /// Panics if rhs is zero, see try_div_with_rem
         pub fn div_with_rem(&self, rhs: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+            self.long_division(rhs, false)
+        }
+
+        /// `self = rhs * q + r` with `deg r < deg rhs`, the ratios of q are found by left_div.
+        /// The same as div_with_rem if the ratios commute. Panics if rhs is zero
+        pub fn left_div_with_rem(&self, rhs: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+            self.long_division(rhs, true)
+        }
+
+        fn long_division(&self, rhs: &Polynomial<T>, left: bool) -> (Polynomial<T>, Polynomial<T>) {
             if rhs.is_zero() {
                 panic!("dividing by zero Polynomial");
             }
 
-            let deg_s = self.deg();
             let deg_r = rhs.deg();
+            let mut r = self.clone();
 
-            if deg_s < deg_r {
-                return (polynom!(T::zero()), self.clone());
+            if r.deg() < deg_r {
+                return (polynom!(T::zero()), r);
             }
 
-            let sub_ratio = &self.ratios[deg_s]/&rhs.ratios[deg_r];
+            let mut q = vec![T::zero(); r.deg() - deg_r + 1];
 
-            let f1 = monom!(T::one(); deg_s - deg_r);
-            let f1 = &(&f1 * rhs) * (&sub_ratio);
-            let f1 = self - &f1;
+            while !r.is_zero() && r.deg() >= deg_r {
+                let deg = r.deg();
+                let lead = r.ratios[deg].clone();
 
-            if f1.is_zero() {
-                return (&monom!(T::one(); deg_s - deg_r) * (&sub_ratio), polynom!(T::zero()));
-            }
+                //x commutes with the ratios, so only the side of the ratio matters
+                let ratio = if left {lead.left_div(rhs.ratios[deg_r].clone())} else {lead / rhs.ratios[deg_r].clone()};
 
-            let (q1, r1) = f1.div_with_rem(rhs);
+                for i in 0..deg_r {
+                    let val = if left {&rhs.ratios[i] * &ratio} else {&ratio * &rhs.ratios[i]};
 
-            let q = monom!(T::one(); deg_s - deg_r) * (sub_ratio) + q1;
-            let r = r1;
+                    r.ratios[deg - deg_r + i] -= val;
+                }
 
-            (q, r)
-                
+                r.ratios[deg] = T::zero();
+                q[deg - deg_r] = ratio;
+
+                if deg == 0 {
+                    break;
+                }
+
+                r.ratios.truncate(deg);
+                r.rm_lead_zero();
             }
+
+            let mut q = Polynomial {ratios: q};
+            q.rm_lead_zero();
+
+            (q, r)
+        }
+    }
+
+    impl<T: DivisionRing + Clone> Div for Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+        type Output = Polynomial<T>;
+
+        /// The quotient of the division with remainder
+        fn div(self, rhs: Self) -> Self::Output {
+            let (q, _) = self.div_with_rem(&rhs);
+
+            q
+        }
+    }
+
+    impl<T: DivisionRing + Clone> DivAssign for Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+        fn div_assign(&mut self, rhs: Self) {
+            let (q, _) = self.div_with_rem(&rhs);
+
+            *self = q;
+        }
     }
 
-    impl<T: Field + Clone> Rem<&Polynomial<T>> for &Polynomial<T>
+    impl<T: DivisionRing + Clone> Rem<&Polynomial<T>> for &Polynomial<T>
     where for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
         type Output = Polynomial<T>;
 
         fn rem(self, rhs: &Polynomial<T>) -> Self::Output {
-            let (_, r) = self.div_with_rem(&rhs);
+            let (_, r) = self.div_with_rem(rhs);
 
             r
         }
@@ -441,21 +515,241 @@ pub mod any_pnm{
     impl<T: Field + Clone> Gcd for Polynomial<T>
     where for <'a> &'a T: Mul<&'a T, Output = T> +  Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T>{
         fn gcd(&self, rhs: &Self) -> Self{
+            if rhs.is_zero() {
+                return self.clone();
+            }
+
             let r = self % rhs;
 
             if r.is_zero() {
                 return rhs.clone();
             }
 
-            r.gcd(rhs)
+            rhs.gcd(&r)
         }
     }
 
-    impl<T: Field + Clone> AssAdd for Polynomial<T> {}
-    impl<T: Field + Clone> ComAdd for Polynomial<T> {}
+    impl<T: Field + Clone> EuclidDiv for Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> +  Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T>{
+        fn euclid_div(&self, rhs: &Self) -> Self {
+            self.div_with_rem(rhs).0
+        }
+
+        fn euclid_rem(&self, rhs: &Self) -> Self {
+            self.div_with_rem(rhs).1
+        }
+    }
+
+    impl<T: Field + Clone> EuclideanDomain for Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> +  Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T>{
+        fn norm(&self) -> u128 {
+            if self.is_zero() {0} else {self.deg() as u128 + 1}
+        }
+
+        fn normalizer(&self) -> Self {
+            if self.is_zero() {
+                return Polynomial::<T>::one();
+            }
+
+            Polynomial::new(vec![T::one() / self.lead().clone()])
+        }
+    }
+
+    impl<T: Field + Clone> Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> +  Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T>{
+        /// The polynomial divided by its leading ratio, zero stays zero
+        pub fn monic(&self) -> Polynomial<T> {
+            let lead = self.lead();
+
+            if lead.is_zero() {
+                return self.clone();
+            }
+
+            Polynomial {ratios: self.ratios.iter().map(|v| v / lead).collect()}
+        }
+
+        /// Extended Euclidean algorithm: `(g, s, t)` with `s * self + t * rhs = g`, where g is the monic gcd
+        pub fn ext_gcd(&self, rhs: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+            let (mut r0, mut s0, mut t0) = (self.clone(), polynom!(T::one()), polynom!(T::zero()));
+            let (mut r1, mut s1, mut t1) = (rhs.clone(), polynom!(T::zero()), polynom!(T::one()));
+
+            while !r1.is_zero() {
+                let (q, r) = r0.div_with_rem(&r1);
+
+                let s = s0 - &q * &s1;
+                let t = t0 - &q * &t1;
+
+                (r0, s0, t0) = (r1, s1, t1);
+                (r1, s1, t1) = (r, s, t);
+            }
+
+            let lead = r0.lead().clone();
+
+            if lead.is_zero() {
+                return (r0, s0, t0);
+            }
+
+            let inv = T::one() / lead;
+
+            (r0 * inv.clone(), s0 * inv.clone(), t0 * inv)
+        }
+
+        /// Yun's algorithm: monic pairwise coprime square-free factors with their multiplicities,
+        /// so that the polynomial equals its leading ratio times the product of `factor^multiplicity`.
+        /// Assumes a field of characteristic zero
+        pub fn square_free_factorization(&self) -> Vec<(Polynomial<T>, usize)> {
+            let f = self.monic();
+
+            if f.deg() == 0 {
+                return Vec::new();
+            }
+
+            let df = f.derivative();
+            let a = f.gcd(&df).monic();
+
+            let mut b = f.div_with_rem(&a).0;
+            let c = df.div_with_rem(&a).0;
+            let mut d = c - b.derivative();
+
+            let mut res = Vec::new();
+            let mut i = 1;
+
+            while b.deg() > 0 {
+                let a = b.gcd(&d).monic();
+
+                b = b.div_with_rem(&a).0;
+                let c = d.div_with_rem(&a).0;
+                d = c - b.derivative();
+
+                if a.deg() > 0 {
+                    res.push((a, i));
+                }
+
+                i += 1;
+            }
+
+            res
+        }
+    }
+
+    impl<T: DivisionRing + ApproxEq> ApproxEq for Polynomial<T> {
+        fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+            let zero = T::zero();
+            let len = self.ratios.len().max(other.ratios.len());
+
+            (0..len).all(|i| {
+                let l = self.ratios.get(i).unwrap_or(&zero);
+                let r = other.ratios.get(i).unwrap_or(&zero);
+
+                l.approx_eq(r, tol)
+            })
+        }
+    }
+
+    //Tolerance-aware versions of the methods above, meant for float coefficients
+    //where cancellation leaves tiny non-zero leading ratios
+    impl<T: DivisionRing + Clone + ApproxEq> Polynomial<T>
+    where for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Sub<&'a T, Output = T> {
+        /// Degree of the polynomial ignoring leading ratios that are approximately zero
+        pub fn deg_approx(&self, tol: Tolerance) -> usize {
+            (1..self.ratios.len()).rev().find(|&i| !self.ratios[i].approx_zero(tol)).unwrap_or(0)
+        }
+
+        pub fn rm_lead_zero_approx(&mut self, tol: Tolerance) {
+            self.ratios.truncate(self.deg_approx(tol) + 1);
+        }
+
+        pub fn is_zero_approx(&self, tol: Tolerance) -> bool {
+            self.deg_approx(tol) == 0 && self.ratios[0].approx_zero(tol)
+        }
+
+        pub fn try_div_with_rem_approx(&self, rhs: &Polynomial<T>, tol: Tolerance) -> Result<(Polynomial<T>, Polynomial<T>), MathError> {
+            if rhs.is_zero_approx(tol) {
+                return Err(MathError::DivisionByZero);
+            }
+
+            Ok(self.div_with_rem_approx(rhs, tol))
+        }
+
+        pub fn div_with_rem_approx(&self, rhs: &Polynomial<T>, tol: Tolerance) -> (Polynomial<T>, Polynomial<T>) {
+            if rhs.is_zero_approx(tol) {
+                panic!("dividing by zero Polynomial");
+            }
+
+            let mut rhs = rhs.clone();
+            rhs.rm_lead_zero_approx(tol);
+
+            let mut r = self.clone();
+            r.rm_lead_zero_approx(tol);
+
+            let deg_r = rhs.deg();
+
+            if r.deg() < deg_r {
+                return (polynom!(T::zero()), r);
+            }
+
+            let mut q = vec![T::zero(); r.deg() - deg_r + 1];
+
+            while !r.is_zero_approx(tol) && r.deg() >= deg_r {
+                let deg = r.deg();
+                let ratio = &r.ratios[deg] / &rhs.ratios[deg_r];
+
+                for i in 0..deg_r {
+                    r.ratios[deg - deg_r + i] -= &ratio * &rhs.ratios[i];
+                }
+
+                //the leading ratio is cancelled exactly instead of leaving a rounding error behind
+                r.ratios[deg] = T::zero();
+                q[deg - deg_r] = ratio;
+
+                if deg == 0 {
+                    break;
+                }
+
+                r.ratios.truncate(deg);
+                r.rm_lead_zero_approx(tol);
+            }
+
+            let mut q = Polynomial {ratios: q};
+            q.rm_lead_zero();
+
+            if r.is_zero_approx(tol) {
+                r = polynom!(T::zero());
+            }
+
+            (q, r)
+        }
+
+        /// Monic greatest common divisor, remainders that are approximately zero are treated as zero
+        pub fn gcd_approx(&self, rhs: &Polynomial<T>, tol: Tolerance) -> Polynomial<T> {
+            let mut a = self.clone();
+            let mut b = rhs.clone();
+
+            a.rm_lead_zero_approx(tol);
+            b.rm_lead_zero_approx(tol);
+
+            while !b.is_zero_approx(tol) {
+                let (_, r) = a.div_with_rem_approx(&b, tol);
+
+                a = b;
+                b = r;
+            }
+
+            let lead = a.ratios[a.deg()].clone();
+
+            if lead.is_zero() {
+                return a;
+            }
+
+            Polynomial {ratios: a.ratios.iter().map(|v| v / &lead).collect()}
+        }
+    }
+
+    impl<T: DivisionRing + Clone> AssAdd for Polynomial<T> {}
+    impl<T: DivisionRing + Clone> ComAdd for Polynomial<T> {}
 
     impl<T> Ring for Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn zero() -> Polynomial<T> {
             Polynomial::new(vec![T::zero()])
@@ -463,7 +757,7 @@ pub mod any_pnm{
     }
 
     impl<T> UnRing for Polynomial<T>
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T>{
        fn one() -> Self {
            Polynomial::new(vec![T::one()])
@@ -471,7 +765,7 @@ pub mod any_pnm{
     }
 
     impl<T> AssMul for Polynomial<T> 
-    where T: Field + Clone,
+    where T: DivisionRing + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {}
 
     impl<T> ComMul for Polynomial<T>
@@ -483,7 +777,7 @@ pub mod any_pnm{
     for <'a> &'a T: Mul<&'a T, Output = T> {}
 
     impl<T> Meta for Polynomial<T>
-    where T: Field + Meta + Clone,
+    where T: DivisionRing + Meta + Clone,
     for <'a> &'a T: Mul<&'a T, Output = T> {
         fn non_zero () -> Self {
             Polynomial::<T>::one()
@@ -495,3 +789,65 @@ pub mod any_pnm{
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::any_pnm::Polynomial;
+    use tech::{Meta, ApproxEq, Tolerance, MathError};
+
+    #[test]
+    fn name_of_the_ratios() {
+        assert_eq!(Polynomial::<f64>::name(), "Polynomial<f64>");
+    }
+
+    #[test]
+    fn eval_by_horner() {
+        //x^3 - 2x + 5
+        let p = Polynomial::new(vec![1.0, 0.0, -2.0, 5.0]);
+
+        assert_eq!(p.eval(&2.0), 9.0);
+        assert_eq!(p.eval(&0.0), 5.0);
+        assert_eq!(p.eval_in(&-1.0), 6.0);
+        assert_eq!(p.evaluate(2.0), 9.0);
+    }
+
+    #[test]
+    fn degree_ignores_tiny_leading_ratios() {
+        let tol = Tolerance::default();
+        let p = Polynomial::new(vec![1e-15, 1.0, 2.0]);
+
+        assert_eq!(p.deg(), 2);
+        assert_eq!(p.deg_approx(tol), 1);
+        assert!(Polynomial::new(vec![1e-15, -1e-14]).is_zero_approx(tol));
+        assert!(!p.is_zero_approx(tol));
+    }
+
+    #[test]
+    fn division_with_rounding_errors() {
+        let tol = Tolerance::default();
+        let b = &Polynomial::new(vec![1.0, -0.1]) * &Polynomial::new(vec![1.0, -0.3]);
+        let a = &b * &Polynomial::new(vec![1.0, 2.0]);
+
+        let (q, r) = a.div_with_rem_approx(&b, tol);
+        assert!(q.approx_eq(&Polynomial::new(vec![1.0, 2.0]), tol));
+        assert!(r.is_zero_approx(tol));
+
+        //1e-16x^2 + x - 1 is treated as x - 1
+        let (q, r) = Polynomial::new(vec![1.0, 0.0, -1.0]).div_with_rem_approx(&Polynomial::new(vec![1e-16, 1.0, -1.0]), tol);
+        assert!(q.approx_eq(&Polynomial::new(vec![1.0, 1.0]), tol));
+        assert!(r.is_zero_approx(tol));
+
+        assert_eq!(a.try_div_with_rem_approx(&Polynomial::new(vec![1e-16, 0.0]), tol), Err(MathError::DivisionByZero));
+    }
+
+    #[test]
+    fn gcd_with_rounding_errors() {
+        let tol = Tolerance::default();
+        let common = Polynomial::new(vec![1.0, -0.1]);
+        let a = &(&common * &Polynomial::new(vec![1.0, -0.3])) * &Polynomial::new(vec![1.0, 2.0]);
+        let b = &common * &Polynomial::new(vec![3.0, 15.0]);
+
+        assert!(a.gcd_approx(&b, tol).approx_eq(&common, Tolerance::absolute(1e-9)));
+        assert!(a.gcd_approx(&Polynomial::new(vec![1.0, 7.0]), tol).approx_eq(&Polynomial::new(vec![1.0]), tol));
+    }
+}
@@ -0,0 +1,348 @@
+//! # quaternion
+//!
+//! Quaternions over any ring from the tech crate, the first non-commutative ring of the workspace
+
+pub mod qtrn {
+    use tech::{Ring, UnRing, DivisionRing, Field, Meta, AssAdd, ComAdd, AssMul, ComMul, ApproxEq, Tolerance, MathError, split_sign};
+    use std::{
+        ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+        fmt::Display,
+    };
+
+    /// `w + xi + yj + zk` with `i^2 = j^2 = k^2 = ijk = -1`
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Quaternion<T: Ring> {
+        w: T,
+        x: T,
+        y: T,
+        z: T,
+    }
+
+    impl<T: Ring> Quaternion<T> {
+        pub fn new(w: T, x: T, y: T, z: T) -> Quaternion<T> {
+            Quaternion {w, x, y, z}
+        }
+
+        /// The real part
+        pub fn w(&self) -> &T {
+            &self.w
+        }
+
+        pub fn x(&self) -> &T {
+            &self.x
+        }
+
+        pub fn y(&self) -> &T {
+            &self.y
+        }
+
+        pub fn z(&self) -> &T {
+            &self.z
+        }
+    }
+
+    impl<T: Ring + Clone> Quaternion<T> {
+        pub fn from_scalar(w: T) -> Quaternion<T> {
+            Quaternion {w, x: T::zero(), y: T::zero(), z: T::zero()}
+        }
+
+        /// The pure quaternion `xi + yj + zk`
+        pub fn from_vector([x, y, z]: [T; 3]) -> Quaternion<T> {
+            Quaternion {w: T::zero(), x, y, z}
+        }
+
+        pub fn scalar(&self) -> T {
+            self.w.clone()
+        }
+
+        pub fn vector(&self) -> [T; 3] {
+            [self.x.clone(), self.y.clone(), self.z.clone()]
+        }
+
+        pub fn conjugated(&self) -> Quaternion<T> {
+            Quaternion {
+                w: self.w.clone(),
+                x: T::zero() - self.x.clone(),
+                y: T::zero() - self.y.clone(),
+                z: T::zero() - self.z.clone(),
+            }
+        }
+
+        /// The four dimensional dot product
+        pub fn dot(&self, rhs: &Quaternion<T>) -> T {
+            self.w.clone() * rhs.w.clone() + self.x.clone() * rhs.x.clone() +
+            self.y.clone() * rhs.y.clone() + self.z.clone() * rhs.z.clone()
+        }
+
+        /// `q * q.conjugated()`, the square of the norm
+        pub fn norm_squared(&self) -> T {
+            self.dot(self)
+        }
+
+        fn map(&self, f: impl Fn(T) -> T) -> Quaternion<T> {
+            Quaternion {w: f(self.w.clone()), x: f(self.x.clone()), y: f(self.y.clone()), z: f(self.z.clone())}
+        }
+    }
+
+    impl<T: Field + Clone> Quaternion<T> {
+        /// `conjugated / norm_squared`, fails with NonInvertible for zero
+        pub fn inverse(&self) -> Result<Quaternion<T>, MathError> {
+            let norm = self.norm_squared();
+
+            if norm.is_zero() {
+                return Err(MathError::NonInvertible);
+            }
+
+            Ok(self.conjugated().map(|v| v / norm.clone()))
+        }
+    }
+
+    impl<T: Ring + Clone> Add for &Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Quaternion {
+                w: self.w.clone() + rhs.w.clone(),
+                x: self.x.clone() + rhs.x.clone(),
+                y: self.y.clone() + rhs.y.clone(),
+                z: self.z.clone() + rhs.z.clone(),
+            }
+        }
+    }
+
+    impl<T: Ring + Clone> Sub for &Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Quaternion {
+                w: self.w.clone() - rhs.w.clone(),
+                x: self.x.clone() - rhs.x.clone(),
+                y: self.y.clone() - rhs.y.clone(),
+                z: self.z.clone() - rhs.z.clone(),
+            }
+        }
+    }
+
+    /// The Hamilton product, `i * j = k` but `j * i = -k`
+    impl<T: Ring + Clone> Mul for &Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            let (a, b) = (self, rhs);
+
+            Quaternion {
+                w: a.w.clone() * b.w.clone() - a.x.clone() * b.x.clone() - a.y.clone() * b.y.clone() - a.z.clone() * b.z.clone(),
+                x: a.w.clone() * b.x.clone() + a.x.clone() * b.w.clone() + a.y.clone() * b.z.clone() - a.z.clone() * b.y.clone(),
+                y: a.w.clone() * b.y.clone() - a.x.clone() * b.z.clone() + a.y.clone() * b.w.clone() + a.z.clone() * b.x.clone(),
+                z: a.w.clone() * b.z.clone() + a.x.clone() * b.y.clone() - a.y.clone() * b.x.clone() + a.z.clone() * b.w.clone(),
+            }
+        }
+    }
+
+    /// A macro for implementing the owned and assigning versions of an operator through the borrowed one
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl<T: Ring + Clone> $op for Quaternion<T> {
+                    type Output = Quaternion<T>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        (&self).$method(&rhs)
+                    }
+                }
+
+                impl<T: Ring + Clone> $op_assign for Quaternion<T> {
+                    fn $method_assign(&mut self, rhs: Self) {
+                        *self = (&*self).$method(&rhs);
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+    /// The right division `self * rhs^-1`, panics if rhs is zero. `left_div` of DivisionRing gives `rhs^-1 * self`
+    impl<T: Field + Clone> Div for &Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            match rhs.inverse() {
+                Ok(inv) => self.mul(&inv),
+                Err(_) => panic!("dividing by zero Quaternion"),
+            }
+        }
+    }
+
+    impl<T: Field + Clone> Div for Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            &self / &rhs
+        }
+    }
+
+    impl<T: Field + Clone> DivAssign for Quaternion<T> {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = &*self / &rhs;
+        }
+    }
+
+    impl<T: Ring + Clone> Mul<T> for Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            self.map(|v| v * rhs.clone())
+        }
+    }
+
+    impl<T: Ring + Clone> MulAssign<T> for Quaternion<T> {
+        fn mul_assign(&mut self, rhs: T) {
+            *self = self.map(|v| v * rhs.clone());
+        }
+    }
+
+    impl<T: Ring + Clone + Div<Output = T>> Div<T> for Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn div(self, rhs: T) -> Self::Output {
+            self.map(|v| v / rhs.clone())
+        }
+    }
+
+    impl<T: Ring + Clone + Div<Output = T>> DivAssign<T> for Quaternion<T> {
+        fn div_assign(&mut self, rhs: T) {
+            *self = self.map(|v| v / rhs.clone());
+        }
+    }
+
+    impl<T: Ring + Clone> Neg for Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn neg(self) -> Self::Output {
+            self.map(|v| T::zero() - v)
+        }
+    }
+
+    impl<T: Ring + Clone> Neg for &Quaternion<T> {
+        type Output = Quaternion<T>;
+
+        fn neg(self) -> Self::Output {
+            self.map(|v| T::zero() - v)
+        }
+    }
+
+    /// `(1 - 2i + 0.5j + 3k)`, the signs of the imaginary parts are written as the operators
+    impl<T: Ring + Display> Display for Quaternion<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "({}", self.w)?;
+
+            for (val, unit) in [(&self.x, 'i'), (&self.y, 'j'), (&self.z, 'k')] {
+                let (negative, abs) = split_sign(val);
+
+                write!(f, " {} {}{}", if negative {'-'} else {'+'}, abs, unit)?;
+            }
+
+            write!(f, ")")
+        }
+    }
+
+    //the Hamilton product is associative but not commutative, so Quaternion is neither ComMul nor an IntegralDomain,
+    //over a field it is a DivisionRing but not a Field
+    impl<T: Ring + Clone> AssAdd for Quaternion<T> {}
+    impl<T: Ring + Clone> ComAdd for Quaternion<T> {}
+    impl<T: Ring + ComMul + AssMul + Clone> AssMul for Quaternion<T> {}
+    impl<T: Field + Clone> DivisionRing for Quaternion<T> {}
+
+    impl<T: Ring + Clone> Ring for Quaternion<T> {
+        fn zero() -> Self {
+            Quaternion::from_scalar(T::zero())
+        }
+    }
+
+    impl<T: UnRing + Clone> UnRing for Quaternion<T> {
+        fn one() -> Self {
+            Quaternion::from_scalar(T::one())
+        }
+    }
+
+    impl<T: UnRing + Meta + Clone> Meta for Quaternion<T> {
+        fn non_zero() -> Self {
+            Quaternion::one()
+        }
+
+        fn name() -> String {
+            format!("Quaternion<{}>", T::name())
+        }
+    }
+
+    impl<T: Ring + ApproxEq> ApproxEq for Quaternion<T> {
+        fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+            self.w.approx_eq(&other.w, tol) && self.x.approx_eq(&other.x, tol) &&
+            self.y.approx_eq(&other.y, tol) && self.z.approx_eq(&other.z, tol)
+        }
+    }
+}
+
+pub mod rotation;
+
+#[cfg(test)]
+mod tests {
+    use crate::qtrn::Quaternion;
+    use fraction::fract::Fraction;
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::{Ring, UnRing, Meta, ApproxEq, Tolerance, MathError};
+
+    #[test]
+    fn hamilton_product() {
+        let i = Quaternion::new(0i64, 1, 0, 0);
+        let j = Quaternion::new(0i64, 0, 1, 0);
+        let k = Quaternion::new(0i64, 0, 0, 1);
+
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, -Quaternion::one());
+        assert_eq!(i * j * k, -Quaternion::one());
+    }
+
+    #[test]
+    fn inverse() {
+        let q = Quaternion::new(1.0, -2.0, 0.5, 3.0);
+
+        assert!((q * q.inverse().unwrap()).approx_eq(&Quaternion::one(), Tolerance::default()));
+        assert!((q / q).approx_eq(&Quaternion::one(), Tolerance::default()));
+        assert_eq!(Quaternion::<f64>::zero().inverse(), Err(MathError::NonInvertible));
+    }
+
+    #[test]
+    fn display_subtracts_negative_imaginary_parts() {
+        assert_eq!(Quaternion::new(1.0, -2.0, 0.5, 3.0).to_string(), "(1 - 2i + 0.5j + 3k)");
+        assert_eq!(Quaternion::new(-1i64, 0, 0, -4).to_string(), "(-1 + 0i + 0j - 4k)");
+
+        let f = |n: i64, d: i64| Fraction::new(n, d);
+        assert_eq!(Quaternion::new(f(1, 2), f(-1, 3), f(2, 1), f(3, 4)).to_string(), "(1/2 - (1/3)i + (2/1)j + (3/4)k)");
+    }
+
+    #[test]
+    fn name_of_the_parts() {
+        assert_eq!(Quaternion::<Fraction<i64>>::name(), "Quaternion<Fraction<i64>>");
+    }
+
+    #[test]
+    fn polynomial_division_on_either_side() {
+        let q = |w: f64, x: f64, y: f64, z: f64| Quaternion::new(w, x, y, z);
+
+        //a = i x^2 + k x + 1, b = j x + i
+        let a = polynom![q(0.0, 1.0, 0.0, 0.0), q(0.0, 0.0, 0.0, 1.0), q(1.0, 0.0, 0.0, 0.0)];
+        let b = polynom![q(0.0, 0.0, 1.0, 0.0), q(0.0, 1.0, 0.0, 0.0)];
+
+        let (right_q, right_r) = a.div_with_rem(&b);
+        let (left_q, left_r) = a.left_div_with_rem(&b);
+
+        assert_eq!(&(&right_q * &b) + &right_r, a);
+        assert_eq!(&(&b * &left_q) + &left_r, a);
+        assert_ne!(right_q, left_q);
+        assert_eq!(right_q, polynom![q(0.0, 0.0, 0.0, -1.0), q(1.0, 1.0, 0.0, 0.0)]);
+        assert_eq!(left_q, polynom![q(0.0, 0.0, 0.0, 1.0), q(1.0, -1.0, 0.0, 0.0)]);
+    }
+}
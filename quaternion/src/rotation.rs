@@ -0,0 +1,244 @@
+//! Exponential, logarithm and interpolation of float quaternions,
+//! and unit quaternions as rotations of the three dimensional space.
+//! The methods exist for f32 and f64, so float literals need a type: `Quaternion::<f64>::from_axis_angle`
+
+use crate::qtrn::Quaternion;
+use matrix::mtrx::{Matrix, Square};
+
+/// A macro for implementing the float methods for f32 and f64
+macro_rules! implFloat {
+    ($($typ: ident),*) => {
+        $(
+            impl Quaternion<$typ> {
+                pub fn norm(&self) -> $typ {
+                    self.norm_squared().sqrt()
+                }
+
+                /// Panics for zero
+                pub fn normalized(&self) -> Quaternion<$typ> {
+                    let norm = self.norm();
+
+                    if norm == 0.0 {
+                        panic!("Normalizing a zero Quaternion");
+                    }
+
+                    *self / norm
+                }
+
+                /// Length of the vector part
+                fn vector_norm(&self) -> $typ {
+                    (self.x() * self.x() + self.y() * self.y() + self.z() * self.z()).sqrt()
+                }
+
+                pub fn exp(&self) -> Quaternion<$typ> {
+                    let v = self.vector_norm();
+                    let e = self.w().exp();
+
+                    if v == 0.0 {
+                        return Quaternion::from_scalar(e);
+                    }
+
+                    let [x, y, z] = self.vector().map(|c| c * e * v.sin() / v);
+
+                    Quaternion::new(e * v.cos(), x, y, z)
+                }
+
+                /// The principal logarithm, negative reals get the vector part `πi`
+                pub fn ln(&self) -> Quaternion<$typ> {
+                    let norm = self.norm();
+                    let v = self.vector_norm();
+
+                    if v == 0.0 {
+                        let x = if *self.w() < 0.0 {std::$typ::consts::PI} else {0.0};
+
+                        return Quaternion::new(norm.ln(), x, 0.0, 0.0);
+                    }
+
+                    let angle = v.atan2(*self.w());
+                    let [x, y, z] = self.vector().map(|c| c * angle / v);
+
+                    Quaternion::new(norm.ln(), x, y, z)
+                }
+
+                /// `exp(t * ln(self))`
+                pub fn powf(&self, t: $typ) -> Quaternion<$typ> {
+                    (self.ln() * t).exp()
+                }
+
+                /// Spherical linear interpolation between the unit quaternions self (t = 0) and rhs (t = 1)
+                /// along the shorter arc
+                pub fn slerp(&self, rhs: &Quaternion<$typ>, t: $typ) -> Quaternion<$typ> {
+                    let mut dot = self.dot(rhs);
+                    let mut end = *rhs;
+
+                    //q and -q are the same rotation
+                    if dot < 0.0 {
+                        dot = -dot;
+                        end = -end;
+                    }
+
+                    //sin(theta) is too small to divide by, the arc is almost a line
+                    if dot > 1.0 - 16.0 * <$typ>::EPSILON {
+                        return (*self * (1.0 - t) + end * t).normalized();
+                    }
+
+                    let theta = dot.acos();
+
+                    (*self * (((1.0 - t) * theta).sin()) + end * ((t * theta).sin())) / theta.sin()
+                }
+
+                /// Rotation by angle (in radians, counterclockwise looking against the axis) around the axis.
+                /// Panics if the axis is zero
+                pub fn from_axis_angle(axis: [$typ; 3], angle: $typ) -> Quaternion<$typ> {
+                    let [x, y, z] = Quaternion::from_vector(axis).normalized().vector().map(|c| c * (angle / 2.0).sin());
+
+                    Quaternion::new((angle / 2.0).cos(), x, y, z)
+                }
+
+                /// Unit axis and angle in `[0, π]` of the rotation, the identity gives the axis `[1, 0, 0]`.
+                /// Panics for zero
+                pub fn to_axis_angle(&self) -> ([$typ; 3], $typ) {
+                    let mut q = self.normalized();
+
+                    if *q.w() < 0.0 {
+                        q = -q;
+                    }
+
+                    let v = q.vector_norm();
+
+                    if v == 0.0 {
+                        return ([1.0, 0.0, 0.0], 0.0);
+                    }
+
+                    (q.vector().map(|c| c / v), 2.0 * v.atan2(*q.w()))
+                }
+
+                /// `self * v * self^-1`, a rotation for unit quaternions. Panics for zero
+                pub fn rotate(&self, v: [$typ; 3]) -> [$typ; 3] {
+                    let norm = self.norm_squared();
+
+                    if norm == 0.0 {
+                        panic!("Rotating by a zero Quaternion");
+                    }
+
+                    (*self * Quaternion::from_vector(v) * self.conjugated()).vector().map(|c| c / norm)
+                }
+
+                /// The matrix of `rotate`, acting on column vectors. Panics for zero
+                pub fn to_rotation_matrix(&self) -> Square<$typ, 3> {
+                    let norm = self.norm_squared();
+
+                    if norm == 0.0 {
+                        panic!("Rotation matrix of a zero Quaternion");
+                    }
+
+                    let s = 2.0 / norm;
+                    let (w, x, y, z) = (*self.w(), *self.x(), *self.y(), *self.z());
+
+                    Square::new(Matrix::new(3, 3, vec![
+                        1.0 - s * (y * y + z * z), s * (x * y - z * w), s * (x * z + y * w),
+                        s * (x * y + z * w), 1.0 - s * (x * x + z * z), s * (y * z - x * w),
+                        s * (x * z - y * w), s * (y * z + x * w), 1.0 - s * (x * x + y * y),
+                    ]))
+                }
+
+                /// Unit quaternion with a non-negative w of a rotation matrix (Shepperd's method),
+                /// m is assumed to be orthogonal with the determinant one
+                pub fn from_rotation_matrix(m: &Square<$typ, 3>) -> Quaternion<$typ> {
+                    let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+
+                    //the largest of w, x, y, z is computed from the diagonal, the rest from the other entries
+                    let q = if trace > 0.0 {
+                        let s = (trace + 1.0).sqrt() * 2.0;
+
+                        Quaternion::new(s / 4.0, (m[(2, 1)] - m[(1, 2)]) / s, (m[(0, 2)] - m[(2, 0)]) / s, (m[(1, 0)] - m[(0, 1)]) / s)
+                    } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+                        let s = (1.0 + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * 2.0;
+
+                        Quaternion::new((m[(2, 1)] - m[(1, 2)]) / s, s / 4.0, (m[(0, 1)] + m[(1, 0)]) / s, (m[(0, 2)] + m[(2, 0)]) / s)
+                    } else if m[(1, 1)] > m[(2, 2)] {
+                        let s = (1.0 + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * 2.0;
+
+                        Quaternion::new((m[(0, 2)] - m[(2, 0)]) / s, (m[(0, 1)] + m[(1, 0)]) / s, s / 4.0, (m[(1, 2)] + m[(2, 1)]) / s)
+                    } else {
+                        let s = (1.0 + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * 2.0;
+
+                        Quaternion::new((m[(1, 0)] - m[(0, 1)]) / s, (m[(0, 2)] + m[(2, 0)]) / s, (m[(1, 2)] + m[(2, 1)]) / s, s / 4.0)
+                    };
+
+                    let q = q.normalized();
+
+                    if *q.w() < 0.0 {-q} else {q}
+                }
+            }
+        )*
+    };
+}
+
+implFloat!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::qtrn::Quaternion;
+    use tech::{UnRing, ApproxEq, Tolerance};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn close(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12)
+    }
+
+    #[test]
+    fn rotation_of_vectors() {
+        let q = Quaternion::<f64>::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+
+        assert!(close(q.rotate([1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]));
+        assert!(close(q.rotate([0.0, 0.0, 3.0]), [0.0, 0.0, 3.0]));
+
+        //the norm of q does not matter
+        assert!(close((q * 5.0).rotate([1.0, 2.0, 3.0]), q.rotate([1.0, 2.0, 3.0])));
+    }
+
+    #[test]
+    fn axis_angle_round_trip() {
+        let axis = [1.0 / 3f64.sqrt(); 3];
+        let (res_axis, angle) = Quaternion::<f64>::from_axis_angle(axis, 2.0).to_axis_angle();
+
+        assert!(close(res_axis, axis));
+        assert!((angle - 2.0).abs() < 1e-12);
+
+        assert_eq!(Quaternion::<f64>::one().to_axis_angle(), ([1.0, 0.0, 0.0], 0.0));
+    }
+
+    #[test]
+    fn rotation_matrix_round_trip() {
+        let tol = Tolerance::absolute(1e-12);
+
+        for (axis, angle) in [([1.0, 2.0, 3.0], 0.7), ([0.0, 1.0, 0.0], PI - 0.1), ([1.0, -1.0, 0.5], 3.0)] {
+            let q = Quaternion::<f64>::from_axis_angle(axis, angle);
+            let m = q.to_rotation_matrix();
+
+            assert!(Quaternion::<f64>::from_rotation_matrix(&m).approx_eq(&q, tol));
+
+            let v = [0.3, -1.0, 2.0];
+            let mv: Vec<f64> = (0..3).map(|i| (0..3).map(|j| m[(i, j)] * v[j]).sum()).collect();
+
+            assert!(close([mv[0], mv[1], mv[2]], q.rotate(v)));
+        }
+    }
+
+    #[test]
+    fn exp_ln_and_slerp() {
+        let tol = Tolerance::absolute(1e-12);
+        let q = Quaternion::<f64>::new(0.5, -1.0, 2.0, 0.25);
+
+        assert!(q.ln().exp().approx_eq(&q, tol));
+        assert!(q.powf(2.0).approx_eq(&(q * q), tol));
+
+        let a = Quaternion::<f64>::from_axis_angle([0.0, 0.0, 1.0], 0.2);
+        let b = Quaternion::<f64>::from_axis_angle([0.0, 0.0, 1.0], 1.4);
+
+        assert!(a.slerp(&b, 0.0).approx_eq(&a, tol));
+        assert!(a.slerp(&b, 1.0).approx_eq(&b, tol));
+        assert!(a.slerp(&b, 0.5).approx_eq(&Quaternion::<f64>::from_axis_angle([0.0, 0.0, 1.0], 0.8), tol));
+    }
+}
@@ -0,0 +1,223 @@
+//! Inverse, square root, exponent and logarithm of power series by Newton iteration,
+//! composition, reversion and the series of rational functions
+
+use crate::srs::PowerSeries;
+use fraction::rational_function::RationalFunction;
+use tech::{Ring, UnRing, Field, Meta, MathError};
+use std::ops::{Mul, Div, Neg, Sub};
+
+/// Newton iteration doubles the number of correct ratios, so this many steps reach N of them from one
+fn newton_steps<const N: usize>() -> u32 {
+    usize::BITS - (N - 1).leading_zeros()
+}
+
+impl<T: Ring + Clone, const N: usize> PowerSeries<T, N> {
+    /// `self(rhs(x))`, panics if the constant term of rhs is not zero since the result would need all the ratios of self
+    pub fn compose(&self, rhs: &PowerSeries<T, N>) -> PowerSeries<T, N> {
+        if !rhs.constant_term().is_zero() {
+            panic!("Composing with a PowerSeries with a non-zero constant term");
+        }
+
+        let mut res = PowerSeries::zero();
+
+        for ratio in self.get().iter().rev() {
+            res = &(&res * rhs) + &PowerSeries::new(vec![ratio.clone()]);
+        }
+
+        res
+    }
+}
+
+impl<T: Field + Clone, const N: usize> PowerSeries<T, N> {
+    //the ratio of x^(N-1) would need the one of x^N, it is left zero, so callers must not depend on it
+    fn derivative(&self) -> PowerSeries<T, N> {
+        let mut pow = T::zero();
+
+        PowerSeries::new(self.get().iter().skip(1).map(|ratio| {
+            pow += T::one();
+            pow.clone() * ratio.clone()
+        }).collect())
+    }
+
+    /// The antiderivative with the constant term zero
+    pub fn integral(&self) -> PowerSeries<T, N> {
+        let mut pow = T::zero();
+
+        let ratios = self.get().iter().map(|ratio| {
+            pow += T::one();
+            ratio.clone() / pow.clone()
+        });
+
+        PowerSeries::new(std::iter::once(T::zero()).chain(ratios).collect())
+    }
+
+    /// The series g with `self * g = 1`, fails with NonInvertible if the constant term is zero
+    pub fn inverse(&self) -> Result<PowerSeries<T, N>, MathError> {
+        if self.constant_term().is_zero() {
+            return Err(MathError::NonInvertible);
+        }
+
+        let two = PowerSeries::new(vec![T::one() + T::one()]);
+        let mut res = PowerSeries::new(vec![T::one() / self.constant_term().clone()]);
+
+        //g = g(2 - self g)
+        for _ in 0..newton_steps::<N>() {
+            res = &res * &(&two - &(self * &res));
+        }
+
+        Ok(res)
+    }
+
+    /// The square root with the constant term one, panics if the constant term of self is not one
+    pub fn sqrt(&self) -> PowerSeries<T, N> {
+        if !self.constant_term().is_one() {
+            panic!("Square root of a PowerSeries whose constant term is not one");
+        }
+
+        let half = T::one() / (T::one() + T::one());
+        let mut res = PowerSeries::one();
+
+        //s = (s + self / s) / 2
+        for _ in 0..newton_steps::<N>() {
+            res = (&res + &(self / &res)) * half.clone();
+        }
+
+        res
+    }
+
+    /// Panics if the constant term is not zero, the exponent of it is not a ratio of T in general
+    pub fn exp(&self) -> PowerSeries<T, N> {
+        if !self.constant_term().is_zero() {
+            panic!("Exponent of a PowerSeries with a non-zero constant term");
+        }
+
+        let one = PowerSeries::one();
+        let mut res = PowerSeries::one();
+
+        //g = g(1 - ln(g) + self)
+        for _ in 0..newton_steps::<N>() {
+            res = &res * &(&(&one - &res.ln()) + self);
+        }
+
+        res
+    }
+
+    /// The natural logarithm `integral(self' / self)`, panics if the constant term is not one
+    pub fn ln(&self) -> PowerSeries<T, N> {
+        if !self.constant_term().is_one() {
+            panic!("Logarithm of a PowerSeries whose constant term is not one");
+        }
+
+        //the wrong last ratio of the derivative moves past x^(N-1) when integrated
+        let inv = self.inverse().expect("the constant term is one");
+
+        (&self.derivative() * &inv).integral()
+    }
+
+    /// The series g with `self(g(x)) = x`, fails with NonInvertible
+    /// if the constant term is not zero or the ratio of x is zero
+    pub fn reversion(&self) -> Result<PowerSeries<T, N>, MathError> {
+        let linear = self.get().get(1).cloned().unwrap_or(T::zero());
+
+        if !self.constant_term().is_zero() || linear.is_zero() {
+            return Err(MathError::NonInvertible);
+        }
+
+        let x = PowerSeries::variable();
+        let derivative = self.derivative();
+        let mut res = PowerSeries::new(vec![T::zero(), T::one() / linear]);
+
+        //g = g - (self(g) - x) / self'(g), the wrong last ratio of self' only reaches past x^(N-1)
+        for _ in 0..newton_steps::<N>() {
+            res = &res - &(&(&self.compose(&res) - &x) / &derivative.compose(&res));
+        }
+
+        Ok(res)
+    }
+}
+
+impl<T, const N: usize> PowerSeries<T, N>
+where T: Field + Meta + Clone,
+for <'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T> + Neg<Output = T> + Sub<&'a T, Output = T> {
+    /// The Taylor series at zero, fails with NonInvertible if zero is a pole
+    pub fn from_rational_function(f: &RationalFunction<T>) -> Result<PowerSeries<T, N>, MathError> {
+        let num = PowerSeries::from(f.num().clone());
+        let denom = PowerSeries::<T, N>::from(f.denom().clone());
+
+        Ok(&num * &denom.inverse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::srs::PowerSeries;
+    use fraction::{fract::Rational, rational_function::RationalFunction};
+    use polynomial::{any_pnm::Polynomial, polynom};
+    use tech::{UnRing, MathError};
+
+    type Series = PowerSeries<Rational<i64>, 8>;
+
+    fn r(n: i64, d: i64) -> Rational<i64> {
+        Rational::new(n, d)
+    }
+
+    fn series(ratios: &[i64]) -> Series {
+        PowerSeries::new(ratios.iter().map(|&n| r(n, 1)).collect())
+    }
+
+    #[test]
+    fn exp_and_ln() {
+        let factorials = [1, 1, 2, 6, 24, 120, 720, 5040];
+        let exp = Series::variable().exp();
+
+        assert_eq!(exp.get(), &factorials.map(|k| r(1, k))[..]);
+
+        let s = series(&[0, 1, 3, -2, 0, 5]);
+        assert_eq!(s.exp().ln(), s);
+
+        let t = series(&[1, 1, 2, 0, -1]);
+        assert_eq!(t.ln().exp(), t);
+    }
+
+    #[test]
+    fn inverse() {
+        //1 / (1 - x) = 1 + x + x^2 + ...
+        assert_eq!(series(&[1, -1]).inverse(), Ok(series(&[1; 8])));
+        assert_eq!(Series::variable().inverse(), Err(MathError::NonInvertible));
+        assert_eq!(series(&[2, 3]) / series(&[2, 3]), Series::one());
+    }
+
+    #[test]
+    fn sqrt() {
+        let s = series(&[1, 1]).sqrt();
+
+        assert_eq!(&s * &s, series(&[1, 1]));
+        assert_eq!(&s.get()[..4], &[r(1, 1), r(1, 2), r(-1, 8), r(1, 16)]);
+    }
+
+    #[test]
+    fn reversion() {
+        //x - x^2 reverts to the generating function of the Catalan numbers shifted by x
+        let s = series(&[0, 1, -1]);
+        let rev = s.reversion().unwrap();
+
+        assert_eq!(rev, series(&[0, 1, 1, 2, 5, 14, 42, 132]));
+        assert_eq!(s.compose(&rev), Series::variable());
+        assert_eq!(series(&[1, 1]).reversion(), Err(MathError::NonInvertible));
+        assert_eq!(series(&[0, 0, 1]).reversion(), Err(MathError::NonInvertible));
+    }
+
+    #[test]
+    fn rational_function() {
+        //x / (1 - x - x^2) generates the Fibonacci numbers
+        let f = RationalFunction::<Rational<i64>>::new(polynom![r(1, 1), r(0, 1)], polynom![r(-1, 1), r(-1, 1), r(1, 1)]);
+
+        assert_eq!(Series::from_rational_function(&f), Ok(series(&[0, 1, 1, 2, 3, 5, 8, 13])));
+
+        let pole = RationalFunction::<Rational<i64>>::new(polynom![r(1, 1)], polynom![r(1, 1), r(0, 1)]);
+        assert_eq!(Series::from_rational_function(&pole), Err(MathError::NonInvertible));
+
+        let p: Polynomial<Rational<i64>> = polynom![r(3, 1), r(2, 1), r(1, 1)];
+        assert_eq!(Series::from(p.clone()).to_polynomial(), p);
+    }
+}
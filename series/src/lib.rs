@@ -0,0 +1,277 @@
+//! # series
+//!
+//! Formal power series truncated to a fixed precision, generating functions without truncating
+//! Polynomial products by hand
+
+pub mod srs {
+    use tech::{Ring, UnRing, DivisionRing, Field, Meta, AssAdd, ComAdd, AssMul, ComMul, split_sign};
+    use polynomial::any_pnm::Polynomial;
+    use std::{
+        ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg},
+        fmt::Display,
+    };
+
+    /// `a0 + a1 x + ... + a(N-1) x^(N-1) + O(x^N)`, every operation truncates its result to the precision N
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PowerSeries<T: Ring, const N: usize> {
+        ratios: Vec<T>,
+    }
+
+    impl<T: Ring + Clone, const N: usize> PowerSeries<T, N> {
+        /// Ratios from the constant term up: the ones past `x^(N-1)` are dropped and the missing ones are zero.
+        /// Panics if N is zero
+        pub fn new(mut ratios: Vec<T>) -> PowerSeries<T, N> {
+            if N == 0 {
+                panic!("Creating a PowerSeries with zero precision");
+            }
+
+            ratios.resize(N, T::zero());
+
+            PowerSeries {ratios}
+        }
+
+        /// The ratios from the constant term up, always N of them
+        pub fn get(&self) -> &[T] {
+            &self.ratios[..]
+        }
+
+        pub fn constant_term(&self) -> &T {
+            &self.ratios[0]
+        }
+
+        fn map(&self, f: impl Fn(T) -> T) -> PowerSeries<T, N> {
+            PowerSeries {ratios: self.ratios.iter().cloned().map(f).collect()}
+        }
+    }
+
+    impl<T: UnRing + Clone, const N: usize> PowerSeries<T, N> {
+        /// The series `x`
+        pub fn variable() -> PowerSeries<T, N> {
+            PowerSeries::new(vec![T::zero(), T::one()])
+        }
+    }
+
+    impl<T: DivisionRing + Clone, const N: usize> PowerSeries<T, N> {
+        /// The ratios up to `x^(N-1)` as a polynomial
+        pub fn to_polynomial(&self) -> Polynomial<T> {
+            Polynomial::new(self.ratios.iter().rev().cloned().collect())
+        }
+    }
+
+    /// The series of a polynomial, truncated if its degree is N or more
+    impl<T: DivisionRing + Clone, const N: usize> From<Polynomial<T>> for PowerSeries<T, N> {
+        fn from(p: Polynomial<T>) -> Self {
+            PowerSeries::new(p.get().to_vec())
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Add for &PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            PowerSeries {ratios: self.ratios.iter().zip(rhs.ratios.iter()).map(|(l, r)| l.clone() + r.clone()).collect()}
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Sub for &PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            PowerSeries {ratios: self.ratios.iter().zip(rhs.ratios.iter()).map(|(l, r)| l.clone() - r.clone()).collect()}
+        }
+    }
+
+    /// The product truncated to `O(x^N)`, the terms of degree N and more are never computed
+    impl<T: Ring + Clone, const N: usize> Mul for &PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut ratios = vec![T::zero(); N];
+
+            for (i, l) in self.ratios.iter().enumerate().filter(|(_, l)| !l.is_zero()) {
+                for (res, r) in ratios[i..].iter_mut().zip(rhs.ratios.iter()) {
+                    *res += l.clone() * r.clone();
+                }
+            }
+
+            PowerSeries {ratios}
+        }
+    }
+
+    /// `self * rhs^-1`, panics if the constant term of rhs is zero, see inverse
+    impl<T: Field + Clone, const N: usize> Div for &PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            match rhs.inverse() {
+                Ok(inv) => self.mul(&inv),
+                Err(_) => panic!("dividing by a PowerSeries with a zero constant term"),
+            }
+        }
+    }
+
+    /// A macro for implementing the owned and assigning versions of an operator through the borrowed one
+    macro_rules! implOp {
+        ($($op: ident, $method: ident, $op_assign: ident, $method_assign: ident);*) => {
+            $(
+                impl<T: Ring + Clone, const N: usize> $op for PowerSeries<T, N> {
+                    type Output = PowerSeries<T, N>;
+
+                    fn $method(self, rhs: Self) -> Self::Output {
+                        (&self).$method(&rhs)
+                    }
+                }
+
+                impl<T: Ring + Clone, const N: usize> $op_assign for PowerSeries<T, N> {
+                    fn $method_assign(&mut self, rhs: Self) {
+                        *self = (&*self).$method(&rhs);
+                    }
+                }
+            )*
+        };
+    }
+
+    implOp!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+    impl<T: Field + Clone, const N: usize> Div for PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            &self / &rhs
+        }
+    }
+
+    impl<T: Field + Clone, const N: usize> DivAssign for PowerSeries<T, N> {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = &*self / &rhs;
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Mul<T> for PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            self.map(|v| v * rhs.clone())
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> MulAssign<T> for PowerSeries<T, N> {
+        fn mul_assign(&mut self, rhs: T) {
+            *self = self.map(|v| v * rhs.clone());
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Neg for PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn neg(self) -> Self::Output {
+            self.map(|v| T::zero() - v)
+        }
+    }
+
+    impl<T: Ring + Clone, const N: usize> Neg for &PowerSeries<T, N> {
+        type Output = PowerSeries<T, N>;
+
+        fn neg(self) -> Self::Output {
+            self.map(|v| T::zero() - v)
+        }
+    }
+
+    /// `1 - 2x + (1/2)x^2 + O(x^3)`, the zero ratios are skipped and the signs are written as the operators
+    impl<T: UnRing + Clone + Display, const N: usize> Display for PowerSeries<T, N> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut first = true;
+
+            for (i, ratio) in self.ratios.iter().enumerate().filter(|(_, r)| !r.is_zero()) {
+                if i == 0 {
+                    write!(f, "{}", ratio)?;
+                    first = false;
+                    continue;
+                }
+
+                let (negative, abs) = split_sign(ratio);
+
+                match (first, negative) {
+                    (true, true) => write!(f, "-")?,
+                    (true, false) => (),
+                    (false, true) => write!(f, " - ")?,
+                    (false, false) => write!(f, " + ")?,
+                }
+
+                first = false;
+
+                let unit = ratio.is_one() || (T::zero() - ratio.clone()).is_one();
+
+                if !unit {
+                    write!(f, "{}", abs)?;
+                }
+
+                match i {
+                    1 => write!(f, "x")?,
+                    _ => write!(f, "x^{}", i)?,
+                }
+            }
+
+            if first {
+                write!(f, "O(x^{})", N)
+            } else {
+                write!(f, " + O(x^{})", N)
+            }
+        }
+    }
+
+    //x^(N-1) * x = O(x^N) is zero, so PowerSeries is not an IntegralDomain
+    impl<T: Ring + Clone, const N: usize> AssAdd for PowerSeries<T, N> {}
+    impl<T: Ring + Clone, const N: usize> ComAdd for PowerSeries<T, N> {}
+    impl<T: Ring + AssMul + Clone, const N: usize> AssMul for PowerSeries<T, N> {}
+    impl<T: Ring + ComMul + Clone, const N: usize> ComMul for PowerSeries<T, N> {}
+
+    impl<T: Ring + Clone, const N: usize> Ring for PowerSeries<T, N> {
+        fn zero() -> Self {
+            PowerSeries::new(vec![])
+        }
+    }
+
+    impl<T: UnRing + Clone, const N: usize> UnRing for PowerSeries<T, N> {
+        fn one() -> Self {
+            PowerSeries::new(vec![T::one()])
+        }
+    }
+
+    impl<T: UnRing + Meta + Clone, const N: usize> Meta for PowerSeries<T, N> {
+        fn non_zero() -> Self {
+            PowerSeries::one()
+        }
+
+        fn name() -> String {
+            format!("PowerSeries<{}, {}>", T::name(), N)
+        }
+    }
+}
+
+pub mod functions;
+
+#[cfg(test)]
+mod tests {
+    use crate::srs::PowerSeries;
+    use fraction::fract::Rational;
+
+    #[test]
+    fn display_subtracts_negative_ratios_and_omits_unit_ones() {
+        let r = |n: i64, d: i64| Rational::new(n, d);
+
+        assert_eq!(PowerSeries::<Rational<i64>, 4>::new(vec![r(1, 1), r(-1, 1), r(1, 2)]).to_string(), "1/1 - x + (1/2)x^2 + O(x^4)");
+        assert_eq!(PowerSeries::<f64, 3>::new(vec![0.0, -1.0, 2.5]).to_string(), "-x + 2.5x^2 + O(x^3)");
+        assert_eq!(PowerSeries::<f64, 3>::new(vec![0.0, 0.0, -0.5]).to_string(), "-0.5x^2 + O(x^3)");
+        assert_eq!(PowerSeries::<f64, 3>::new(vec![]).to_string(), "O(x^3)");
+    }
+
+    #[test]
+    fn product_is_truncated() {
+        //(1 + x)^2 with precision 2
+        let s = PowerSeries::<i64, 2>::new(vec![1, 1]);
+
+        assert_eq!((s.clone() * s).get(), &[1, 2]);
+        assert_eq!((PowerSeries::<i64, 3>::variable() * PowerSeries::variable() * PowerSeries::variable()).get(), &[0, 0, 0]);
+    }
+}
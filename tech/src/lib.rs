@@ -41,6 +41,107 @@ macro_rules! implTrait {
         )*
     };
 
+    (Gcd for $($typ: ty),*) => {
+        $(
+            impl Gcd for $typ {
+                fn gcd(&self, rhs: &$typ) -> $typ {
+                    //the absolute values always fit into the unsigned type, so MIN % -1 never happens
+                    let mut a = self.unsigned_abs();
+                    let mut b = rhs.unsigned_abs();
+
+                    while b != 0 {
+                        let r = a % b;
+
+                        a = b;
+                        b = r;
+                    }
+
+                    a as $typ
+                }
+            }
+        )*
+    };
+
+    (Bits for $($typ: ty),*) => {
+        $(
+            impl Bits for $typ {
+                fn bits(&self) -> u32 {
+                    <$typ>::BITS - self.unsigned_abs().leading_zeros()
+                }
+            }
+        )*
+    };
+
+    (OrderedRing for $($typ: ty),*) => {
+        $(
+            impl OrderedRing for $typ {
+                fn signum(&self) -> $typ {
+                    <$typ>::signum(*self)
+                }
+
+                fn abs(&self) -> $typ {
+                    <$typ>::abs(*self)
+                }
+            }
+        )*
+    };
+
+    (EuclidDiv for $($typ: ty),*) => {
+        $(
+            impl EuclidDiv for $typ {
+                fn euclid_div(&self, rhs: &$typ) -> $typ {
+                    self.div_euclid(*rhs)
+                }
+
+                fn euclid_rem(&self, rhs: &$typ) -> $typ {
+                    self.rem_euclid(*rhs)
+                }
+            }
+        )*
+    };
+
+    (EuclideanDomain for $($typ: ty),*) => {
+        $(
+            impl EuclideanDomain for $typ {
+                fn norm(&self) -> u128 {
+                    self.unsigned_abs() as u128
+                }
+
+                fn normalizer(&self) -> $typ {
+                    if *self < 0 {-1} else {1}
+                }
+            }
+        )*
+    };
+
+    (Checked for $($typ: ty),*) => {
+        $(
+            impl Checked for $typ {
+                fn checked_add(&self, rhs: &$typ) -> Option<$typ> {
+                    <$typ>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &$typ) -> Option<$typ> {
+                    <$typ>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &$typ) -> Option<$typ> {
+                    <$typ>::checked_mul(*self, *rhs)
+                }
+            }
+        )*
+    };
+
+    (Magnitude for $($typ: ty),*) => {
+        $(
+            impl Magnitude for $typ {
+                fn magnitude(&self) -> f64 {
+                    self.abs() as f64
+                }
+            }
+        )*
+    };
+
     ($i: ident for $($typ: ty),*) => {
         $(
             impl $i for $typ {}
@@ -48,10 +149,32 @@ macro_rules! implTrait {
     }
 }
 
+/// Special trait that allows to assume that every non-zero element has an inverse, the multiplication need not commute.
+/// `a / b` is the right division `a * b^-1`, left_div divides from the other side
+pub trait DivisionRing
+where Self: UnRing + AssMul + Div<Self, Output = Self> + DivAssign <Self> + Neg<Output = Self> {
+    /// `rhs^-1 * self`
+    fn left_div(self, rhs: Self) -> Self {
+        (Self::one() / rhs) * self
+    }
+
+    /// `self * rhs^-1`, the same as `/`
+    fn right_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+}
+
+implTrait!(DivisionRing for f32, f64);
+
+/// Another name of DivisionRing
+pub trait SkewField: DivisionRing {}
+
+impl<T: DivisionRing> SkewField for T {}
+
 /// Special trait that allows to assume that elements of the type form a field (in algebraic terms). 
-/// Types that implement Field are also assumed to be UnRing and an IntegralDomain
+/// A Field is a commutative DivisionRing, so it is also assumed to be an IntegralDomain
 pub trait Field 
-where Self: UnRing + IntegralDomain + Div<Self, Output = Self> + DivAssign <Self> + Neg<Output = Self> {}
+where Self: DivisionRing + IntegralDomain {}
 
 implTrait!(Field for f32, f64);
 
@@ -131,4 +254,215 @@ implTrait!(Meta for (f32; "f32"), (f64; "f64"), (i8; "i8"), (i16; "i16"), (i32;
 pub trait Gcd 
 where Self: Sized{
     fn gcd(&self, rhs: &Self) -> Self;
-}
\ No newline at end of file
+}
+
+//the gcd of integers is non-negative, except for gcd(MIN, 0) and gcd(MIN, MIN):
+//their value 2^(bits - 1) does not fit and wraps to MIN, which still divides both arguments
+implTrait!(Gcd for i8, i16, i32, i64, i128);
+
+/// Describes a ring with a total order that agrees with adding and multiplying
+pub trait OrderedRing: Ring + PartialOrd {
+    fn signum(&self) -> Self;
+    fn abs(&self) -> Self;
+
+    fn is_positive(&self) -> bool {
+        self > &Self::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self < &Self::zero()
+    }
+}
+
+implTrait!(OrderedRing for i8, i16, i32, i64, i128);
+
+/// Division with the remainder: `a = b * q + r`, where `0 <= r < |b|` for integers
+pub trait EuclidDiv: Sized {
+    fn euclid_div(&self, rhs: &Self) -> Self;
+    fn euclid_rem(&self, rhs: &Self) -> Self;
+}
+
+implTrait!(EuclidDiv for i8, i16, i32, i64, i128);
+
+/// Integral domain whose EuclidDiv remainder is always smaller than the divisor in terms of `norm`
+pub trait EuclideanDomain: IntegralDomain + UnRing + EuclidDiv {
+    /// Zero only for zero: the absolute value of integers, the degree plus one for polynomials
+    fn norm(&self) -> u128;
+    /// The unit that turns the element into its canonical associate:
+    /// the sign for integers, the inverse of the leading ratio for polynomials
+    fn normalizer(&self) -> Self;
+}
+
+implTrait!(EuclideanDomain for i8, i16, i32, i64, i128);
+
+///Trait for getting the number of significant bits in the absolute value
+pub trait Bits {
+    fn bits(&self) -> u32;
+}
+
+implTrait!(Bits for i8, i16, i32, i64, i128);
+
+///Trait for arithmetic that reports an overflow instead of wrapping or panicking
+pub trait Checked: Sized {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+}
+
+implTrait!(Checked for i8, i16, i32, i64, i128);
+
+///Trait for the size of a value, used to choose pivots in fields that are not ordered
+pub trait Magnitude {
+    fn magnitude(&self) -> f64;
+}
+
+implTrait!(Magnitude for f32, f64);
+
+/// Error shared by the fallible operations of the math crates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+    DivisionByZero,
+    Overflow,
+    /// Text that could not be parsed, with the reason
+    Parse(String),
+    /// Inverting an element that has no inverse, like a zero fraction or a non-constant polynomial
+    NonInvertible,
+    /// An iterative method that did not reach the required accuracy
+    NoConvergence,
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathError::DivisionByZero => write!(f, "division by zero"),
+            MathError::Overflow => write!(f, "arithmetic overflow"),
+            MathError::Parse(s) => write!(f, "parse error: {}", s),
+            MathError::NonInvertible => write!(f, "the element has no inverse"),
+            MathError::NoConvergence => write!(f, "the iteration did not converge"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// The sign of a displayed value and its magnitude, parenthesized unless it is a single number like `1.5` or `inf`.
+/// Display impls use it to write the signs of the later terms of a sum as the operators, `(1 - 2i)`
+pub fn split_sign(val: &impl std::fmt::Display) -> (bool, String) {
+    let val = val.to_string();
+
+    let (negative, abs) = match val.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, &val[..]),
+    };
+
+    let atomic = abs.chars().all(|c| c.is_alphanumeric() || c == '.') || (abs.starts_with('(') && abs.ends_with(')'));
+
+    (negative, if atomic {abs.to_string()} else {format!("({})", abs)})
+}
+
+/// Tolerance for comparing values that are subject to rounding errors.
+/// Two values are considered equal if any of the three criteria holds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Maximal absolute difference
+    pub abs: f64,
+    /// Maximal difference relative to the larger of the two values
+    pub rel: f64,
+    /// Maximal distance in units in the last place
+    pub ulps: u64,
+}
+
+impl Tolerance {
+    pub fn new(abs: f64, rel: f64, ulps: u64) -> Tolerance {
+        Tolerance {abs, rel, ulps}
+    }
+
+    pub fn absolute(abs: f64) -> Tolerance {
+        Tolerance {abs, rel: 0.0, ulps: 0}
+    }
+
+    pub fn relative(rel: f64) -> Tolerance {
+        Tolerance {abs: 0.0, rel, ulps: 0}
+    }
+
+    pub fn ulps(ulps: u64) -> Tolerance {
+        Tolerance {abs: 0.0, rel: 0.0, ulps}
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance {abs: 1e-12, rel: 1e-12, ulps: 4}
+    }
+}
+
+///Trait for comparing values of types with rounding errors
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool;
+
+    fn approx_zero(&self, tol: Tolerance) -> bool
+    where Self: Ring {
+        self.approx_eq(&Self::zero(), tol)
+    }
+}
+
+macro_rules! implApproxEq {
+    ($(($typ: ty; $bits: ty)),*) => {
+        $(
+            impl ApproxEq for $typ {
+                fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+                    if self == other {
+                        return true;
+                    }
+
+                    let diff = (self - other).abs() as f64;
+
+                    if diff <= tol.abs || diff <= tol.rel * (self.abs().max(other.abs()) as f64) {
+                        return true;
+                    }
+
+                    if self.is_sign_negative() != other.is_sign_negative() || !self.is_finite() || !other.is_finite() {
+                        return false;
+                    }
+
+                    (self.to_bits() as $bits).abs_diff(other.to_bits() as $bits) as u64 <= tol.ulps
+                }
+            }
+        )*
+    };
+}
+
+implApproxEq!((f32; i32), (f64; i64));
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gcd, split_sign};
+
+    #[test]
+    fn gcd_of_integers() {
+        assert_eq!(12i64.gcd(&-18), 6);
+        assert_eq!((-12i64).gcd(&0), 12);
+        assert_eq!(0i64.gcd(&0), 0);
+    }
+
+    #[test]
+    fn gcd_at_min() {
+        assert_eq!(i64::MIN.gcd(&-1), 1);
+        assert_eq!((-1i64).gcd(&i64::MIN), 1);
+        assert_eq!(i64::MIN.gcd(&6), 2);
+        assert_eq!(i8::MIN.gcd(&-96), 32);
+        //2^63 does not fit, see the comment on the implementation
+        assert_eq!(i64::MIN.gcd(&0), i64::MIN);
+        assert_eq!(i64::MIN.gcd(&i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn split_sign_parenthesizes_compound_values() {
+        assert_eq!(split_sign(&-2.5), (true, "2.5".to_string()));
+        assert_eq!(split_sign(&3), (false, "3".to_string()));
+        assert_eq!(split_sign(&f64::NEG_INFINITY), (true, "inf".to_string()));
+        assert_eq!(split_sign(&"-1/3"), (true, "(1/3)".to_string()));
+        assert_eq!(split_sign(&"(1 + 2i)"), (false, "(1 + 2i)".to_string()));
+        assert_eq!(split_sign(&"1e-3"), (false, "(1e-3)".to_string()));
+    }
+}